@@ -0,0 +1,36 @@
+use chat_core::{
+    models::{admin_action::AdminAction, User},
+    Db, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+
+/// # Query Options
+#[derive(Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsActionLog {
+    /// Only include actions performed on this target
+    target: Option<String>,
+
+    /// Only include actions before this ID
+    before: Option<String>,
+
+    /// Maximum number of entries to return (1-100)
+    limit: Option<i64>,
+}
+
+/// # Fetch Action Log
+///
+/// Fetch the most recent actions taken through the admin API.
+#[openapi(tag = "Admin")]
+#[get("/actions?<options..>")]
+pub async fn req(db: &Db, user: User, options: OptionsActionLog) -> Result<Json<Vec<AdminAction>>> {
+    user.throw_if_not_privileged()?;
+
+    db.fetch_admin_actions(
+        options.target.as_deref(),
+        options.before.as_deref(),
+        options.limit.unwrap_or(50).clamp(1, 100),
+    )
+    .await
+    .map(Json)
+}
@@ -1,8 +1,24 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod action_log;
 mod stats;
+mod user_logout;
+mod user_lookup;
+mod user_servers;
+mod user_set_badges;
+mod user_set_flags;
+mod user_set_ratelimits;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
-    openapi_get_routes_spec![stats::stats]
+    openapi_get_routes_spec![
+        stats::stats,
+        user_lookup::req,
+        user_set_flags::req,
+        user_set_badges::req,
+        user_logout::req,
+        user_servers::req,
+        user_set_ratelimits::req,
+        action_log::req
+    ]
 }
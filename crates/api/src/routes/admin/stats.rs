@@ -1,8 +1,9 @@
-use chat_core::{models::stats::Stats, Db, Result};
+use chat_core::{models::stats::Stats, models::User, Db, Result};
 use rocket::serde::json::Json;
 
 #[openapi(tag = "Admin")]
 #[get("/stats")]
-pub async fn stats(db: &Db) -> Result<Json<Stats>> {
+pub async fn stats(db: &Db, user: User) -> Result<Json<Stats>> {
+    user.throw_if_not_privileged()?;
     Ok(Json(db.generate_stats().await?))
 }
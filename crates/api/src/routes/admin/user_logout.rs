@@ -0,0 +1,68 @@
+use chat_core::{
+    authifier::Authifier,
+    models::{
+        admin_action::{AdminAction, AdminActionType},
+        User,
+    },
+    Db, EmptyResponse, Error, Ref, Result,
+};
+use rocket::{serde::json::Json, State};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Logout Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataLogoutUser {
+    #[validate(length(min = 1, max = 1024))]
+    reason: Option<String>,
+}
+
+/// Remove every session belonging to an account
+pub async fn logout_everywhere(authifier: &Authifier, user_id: &str) -> Result<()> {
+    let account = authifier
+        .database
+        .find_account(user_id)
+        .await
+        .map_err(|_| Error::NotFound)?;
+
+    account
+        .delete_all_sessions(authifier, None)
+        .await
+        .map_err(|_| Error::DatabaseError {
+            operation: "delete_many",
+            with: "sessions",
+        })
+}
+
+/// # Logout User
+///
+/// Invalidate all sessions belonging to a user.
+#[openapi(tag = "Admin")]
+#[post("/users/<target>/logout", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    authifier: &State<Authifier>,
+    user: User,
+    target: Ref,
+    data: Json<DataLogoutUser>,
+) -> Result<EmptyResponse> {
+    user.throw_if_not_privileged()?;
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let target = db.fetch_user(&target.id).await?;
+    logout_everywhere(authifier, &target.id).await?;
+
+    AdminAction::create(
+        db,
+        &user.id,
+        Some(&target.id),
+        AdminActionType::LogoutUser,
+        data.reason,
+    )
+    .await?;
+
+    Ok(EmptyResponse)
+}
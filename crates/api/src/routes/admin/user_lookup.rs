@@ -0,0 +1,70 @@
+use chat_core::{
+    authifier::{util::normalise_email, Authifier},
+    models::{
+        admin_action::{AdminAction, AdminActionType},
+        User,
+    },
+    Db, Error, Result,
+};
+use rocket::{serde::json::Json, State};
+use serde::{Deserialize, Serialize};
+
+/// # Lookup Options
+///
+/// Exactly one of the fields should be provided.
+#[derive(Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsUserLookup {
+    id: Option<String>,
+    username: Option<String>,
+    email: Option<String>,
+}
+
+/// # Lookup User
+///
+/// Find a user by their ID, username or account email.
+#[openapi(tag = "Admin")]
+#[get("/users?<options..>")]
+pub async fn req(
+    db: &Db,
+    authifier: &State<Authifier>,
+    user: User,
+    options: OptionsUserLookup,
+) -> Result<Json<User>> {
+    user.throw_if_not_privileged()?;
+
+    let (query, target) = match (options.id, options.username, options.email) {
+        (Some(id), None, None) => {
+            let target = db.fetch_user(&id).await?;
+            (id, target)
+        }
+        (None, Some(username), None) => {
+            let target = db.fetch_user_by_username(&username).await?;
+            (username, target)
+        }
+        (None, None, Some(email)) => {
+            let account = authifier
+                .database
+                .find_account_by_normalised_email(&normalise_email(email.clone()))
+                .await
+                .map_err(|_| Error::DatabaseError {
+                    operation: "find_one",
+                    with: "account",
+                })?
+                .ok_or(Error::NotFound)?;
+
+            (email, db.fetch_user(&account.id).await?)
+        }
+        _ => return Err(Error::InvalidOperation),
+    };
+
+    AdminAction::create(
+        db,
+        &user.id,
+        Some(&target.id),
+        AdminActionType::LookupUser { query },
+        None,
+    )
+    .await?;
+
+    Ok(Json(target))
+}
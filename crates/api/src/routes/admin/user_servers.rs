@@ -0,0 +1,38 @@
+use chat_core::{
+    models::{
+        admin_action::{AdminAction, AdminActionType},
+        Server, User,
+    },
+    Db, Ref, Result,
+};
+use rocket::serde::json::Json;
+
+/// # Fetch User Servers
+///
+/// Fetch every server a user is a member of.
+#[openapi(tag = "Admin")]
+#[get("/users/<target>/servers")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Vec<Server>>> {
+    user.throw_if_not_privileged()?;
+
+    let target = db.fetch_user(&target.id).await?;
+    let server_ids = db
+        .fetch_all_memberships(&target.id)
+        .await?
+        .into_iter()
+        .map(|member| member.id.server)
+        .collect::<Vec<String>>();
+
+    let servers = db.fetch_servers(&server_ids).await?;
+
+    AdminAction::create(
+        db,
+        &user.id,
+        Some(&target.id),
+        AdminActionType::FetchUserServers,
+        None,
+    )
+    .await?;
+
+    Ok(Json(servers))
+}
@@ -0,0 +1,65 @@
+use chat_core::{
+    models::{
+        admin_action::{AdminAction, AdminActionType},
+        user::PartialUser,
+        User,
+    },
+    Db, EmptyResponse, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Badge Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataSetUserBadges {
+    /// Bitfield of user badges
+    #[validate(range(max = 2047))]
+    badges: u32,
+
+    #[validate(length(min = 1, max = 1024))]
+    reason: Option<String>,
+}
+
+/// # Set User Badges
+///
+/// Replace the badges displayed on a user's profile.
+#[openapi(tag = "Admin")]
+#[patch("/users/<target>/badges", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataSetUserBadges>,
+) -> Result<EmptyResponse> {
+    user.throw_if_not_privileged()?;
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let mut target = db.fetch_user(&target.id).await?;
+    target
+        .update(
+            db,
+            PartialUser {
+                badges: Some(data.badges),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    AdminAction::create(
+        db,
+        &user.id,
+        Some(&target.id),
+        AdminActionType::SetUserBadges {
+            badges: data.badges,
+        },
+        data.reason,
+    )
+    .await?;
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,77 @@
+use chat_core::{
+    authifier::Authifier,
+    models::{
+        admin_action::{AdminAction, AdminActionType},
+        user::{PartialUser, UserFlags},
+        User,
+    },
+    Db, EmptyResponse, Error, Ref, Result,
+};
+use rocket::{serde::json::Json, State};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Flag Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataSetUserFlags {
+    /// Combination of the `Suspended`, `Banned` and `Spam` flags
+    flags: u32,
+
+    #[validate(length(min = 1, max = 1024))]
+    reason: Option<String>,
+}
+
+/// # Set User Flags
+///
+/// Suspend, ban or mark a user as spam.
+/// Suspending or banning a user also logs them out everywhere.
+#[openapi(tag = "Admin")]
+#[patch("/users/<target>/flags", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    authifier: &State<Authifier>,
+    user: User,
+    target: Ref,
+    data: Json<DataSetUserFlags>,
+) -> Result<EmptyResponse> {
+    user.throw_if_not_privileged()?;
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let settable = UserFlags::Suspended as u32 | UserFlags::Banned as u32 | UserFlags::Spam as u32;
+
+    if data.flags & !settable != 0 {
+        return Err(Error::InvalidProperty);
+    }
+
+    let mut target = db.fetch_user(&target.id).await?;
+    let flags = data.flags | (target.flags & UserFlags::Deleted as u32);
+
+    target
+        .update(
+            db,
+            PartialUser {
+                flags: Some(flags),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    if flags & (UserFlags::Suspended as u32 | UserFlags::Banned as u32) != 0 {
+        super::user_logout::logout_everywhere(authifier, &target.id).await?;
+    }
+
+    AdminAction::create(
+        db,
+        &user.id,
+        Some(&target.id),
+        AdminActionType::SetUserFlags { flags },
+        data.reason,
+    )
+    .await?;
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use chat_core::{
+    models::{
+        admin_action::{AdminAction, AdminActionType},
+        ratelimit_events::ratelimit_override::RatelimitOverride,
+        User,
+    },
+    Db, EmptyResponse, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::util::ratelimiter::{invalidate_override, is_bucket};
+
+/// # Ratelimit Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataSetUserRatelimits {
+    /// Requests allowed per window, keyed by bucket name
+    ///
+    /// Pass an empty object to remove all overrides.
+    buckets: HashMap<String, u32>,
+
+    #[validate(length(min = 1, max = 1024))]
+    reason: Option<String>,
+}
+
+/// # Set User Ratelimits
+///
+/// Override the ratelimits applied to a trusted user or bot.
+#[openapi(tag = "Admin")]
+#[put("/users/<target>/ratelimits", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataSetUserRatelimits>,
+) -> Result<EmptyResponse> {
+    user.throw_if_not_privileged()?;
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if !data.buckets.keys().all(|bucket| is_bucket(bucket)) {
+        return Err(Error::InvalidProperty);
+    }
+
+    let target = db.fetch_user(&target.id).await?;
+    if data.buckets.is_empty() {
+        db.delete_ratelimit_override(&target.id).await?;
+    } else {
+        db.set_ratelimit_override(&RatelimitOverride {
+            id: target.id.to_string(),
+            buckets: data.buckets.clone(),
+        })
+        .await?;
+    }
+
+    invalidate_override(&target.id);
+
+    AdminAction::create(
+        db,
+        &user.id,
+        Some(&target.id),
+        AdminActionType::SetRatelimitOverride {
+            buckets: data.buckets,
+        },
+        data.reason,
+    )
+    .await?;
+
+    Ok(EmptyResponse)
+}
@@ -135,7 +135,7 @@ pub async fn req(
             }
 
             if let Some(icon_id) = data.icon {
-                partial.icon = Some(File::use_icon(db, &icon_id, id).await?);
+                partial.icon = Some(File::use_icon(db, &icon_id, id, &user.id).await?);
                 *icon = partial.icon.clone();
             }
 
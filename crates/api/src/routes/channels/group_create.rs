@@ -1,5 +1,10 @@
 use chat_core::{
-    models::{channel::DataCreateGroup, user::RelationshipStatus, Channel, User},
+    models::{
+        channel::DataCreateGroup,
+        ratelimit_events::ratelimit::{RatelimitEvent, RatelimitEventType},
+        user::RelationshipStatus,
+        Channel, User,
+    },
     Database, Error, Result,
 };
 use rocket::{serde::json::Json, State};
@@ -29,5 +34,10 @@ pub async fn create_group(
         }
     }
 
-    Ok(Json(Channel::create_group(db, data, user.id).await?))
+    RatelimitEvent::throw_if_on_cooldown(db, &user.id, RatelimitEventType::GroupCreate).await?;
+
+    let channel = Channel::create_group(db, data, user.id.to_string()).await?;
+    RatelimitEvent::create(db, user.id, RatelimitEventType::GroupCreate).await?;
+
+    Ok(Json(channel))
 }
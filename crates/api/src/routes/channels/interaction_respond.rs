@@ -0,0 +1,32 @@
+use chat_core::{
+    models::{
+        message::{InteractionResponse, MessageInteraction},
+        User,
+    },
+    Db, EmptyResponse, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+
+#[openapi(tag = "Interactions")]
+#[post("/<target>/interactions/<interaction>", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    interaction: Ref,
+    data: Json<InteractionResponse>,
+) -> Result<EmptyResponse> {
+    if user.bot.is_none() {
+        return Err(Error::IsNotBot);
+    }
+
+    let interaction = MessageInteraction::fetch(&interaction.id).await?;
+    if interaction.channel_id != target.id || interaction.bot_id != user.id {
+        return Err(Error::NotFound);
+    }
+
+    interaction
+        .respond(db, data.into_inner())
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -1,5 +1,8 @@
 use chat_core::{
-    models::{Invite, User},
+    models::{
+        ratelimit_events::ratelimit::{RatelimitEvent, RatelimitEventType},
+        Invite, User,
+    },
     permissions::defn::ChannelPermission,
     perms, Db, Error, Ref, Result,
 };
@@ -19,5 +22,10 @@ pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Invite>> {
         .throw_permission_and_view_channel(db, ChannelPermission::InviteOthers)
         .await?;
 
-    Invite::create(db, &user, &channel).await.map(Json)
+    RatelimitEvent::throw_if_on_cooldown(db, &user.id, RatelimitEventType::InviteCreate).await?;
+
+    let invite = Invite::create(db, &user, &channel).await?;
+    RatelimitEvent::create(db, user.id, RatelimitEventType::InviteCreate).await?;
+
+    Ok(Json(invite))
 }
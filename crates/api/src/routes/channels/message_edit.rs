@@ -81,7 +81,12 @@ pub async fn req(
         new_embeds.clear();
 
         for embed in embeds {
-            new_embeds.push(embed.clone().into_embed(db, message.id.clone()).await?);
+            new_embeds.push(
+                embed
+                    .clone()
+                    .into_embed(db, message.id.clone(), Some(&user.id))
+                    .await?,
+            );
         }
     }
 
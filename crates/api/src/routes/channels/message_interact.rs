@@ -0,0 +1,48 @@
+use chat_core::{
+    models::{message::MessageInteraction, User},
+    permissions::defn::ChannelPermission,
+    perms, Db, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Component Interaction
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataMessageInteract {
+    /// Custom ID of the button or select menu used
+    #[validate(length(min = 1, max = 100))]
+    custom_id: String,
+
+    /// Selected values, only for select menus
+    #[validate(length(max = 25))]
+    #[serde(default)]
+    values: Vec<String>,
+}
+
+#[openapi(tag = "Interactions")]
+#[post("/<target>/messages/<msg>/interactions", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    msg: Ref,
+    data: Json<DataMessageInteract>,
+) -> Result<Json<MessageInteraction>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(db, ChannelPermission::ViewChannel)
+        .await?;
+
+    let message = msg.as_message_in(db, channel.id()).await?;
+
+    message
+        .interact(db, &user, data.custom_id, data.values)
+        .await
+        .map(Json)
+}
@@ -8,6 +8,7 @@ mod channel_fetch;
 mod group_add_member;
 mod group_create;
 mod group_remove_member;
+mod interaction_respond;
 mod invite_create;
 mod members_fetch;
 mod message_bulk_delete;
@@ -15,6 +16,7 @@ mod message_clear_reactions;
 mod message_delete;
 mod message_edit;
 mod message_fetch;
+mod message_interact;
 mod message_query;
 mod message_react;
 mod message_search;
@@ -23,6 +25,10 @@ mod message_unreact;
 mod permissions_set;
 mod permissions_set_default;
 mod voice_join;
+mod voice_leave;
+mod voice_state_disconnect;
+mod voice_state_edit;
+mod voice_state_moderate;
 mod webhook_create;
 mod webhook_fetch_all;
 
@@ -47,9 +53,15 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         message_query::req,
         message_search::req,
         message_edit::req,
+        message_interact::req,
+        interaction_respond::req,
         message_react::react_message,
         permissions_set::req,
         voice_join::req,
+        voice_leave::req,
+        voice_state_edit::req,
+        voice_state_moderate::req,
+        voice_state_disconnect::req,
         webhook_create::req,
         webhook_fetch_all::req,
     ]
@@ -2,20 +2,14 @@ use chat_core::{
     models::{Channel, User},
     permissions::defn::ChannelPermission,
     perms,
-    variables::delta::{VOSO_MANAGE_TOKEN, VOSO_URL},
-    Db, Error, Ref, Result,
+    voice::backend::{voice_backend, VoiceCredentials, VoiceGrants},
+    voice_connect, voice_fetch_state, Db, Error, Ref, Result,
 };
 use rocket::serde::json::Json;
-use serde::{Deserialize, Serialize};
-
-#[derive(Serialize, Deserialize, JsonSchema)]
-pub struct CreateVoiceUserResponse {
-    token: String,
-}
 
 #[openapi(tag = "Voice")]
 #[post("/<target>/join_call")]
-pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<CreateVoiceUserResponse>> {
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<VoiceCredentials>> {
     let channel = target.as_channel(db).await?;
     let mut permissions = perms(&user).channel(&channel);
 
@@ -30,58 +24,18 @@ pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<CreateVoiceUse
         _ => {}
     }
 
-    let client = reqwest::Client::new();
-    let result = client
-        .get(&format!("{}/room/{}", *VOSO_URL, channel.id()))
-        .header(
-            reqwest::header::AUTHORIZATION,
-            VOSO_MANAGE_TOKEN.to_string(),
-        )
-        .send()
-        .await;
+    let state = voice_fetch_state(&user.id).await;
+    let grants = VoiceGrants::for_user(db, &user, &channel, state.as_ref()).await?;
 
-    match result {
-        Err(_) => return Err(Error::VosoUnavailable),
-        Ok(result) => match result.status() {
-            reqwest::StatusCode::OK => (),
-            reqwest::StatusCode::NOT_FOUND => {
-                if (client
-                    .post(&format!("{}/room/{}", *VOSO_URL, channel.id()))
-                    .header(
-                        reqwest::header::AUTHORIZATION,
-                        VOSO_MANAGE_TOKEN.to_string(),
-                    )
-                    .send()
-                    .await)
-                    .is_err()
-                {
-                    return Err(Error::VosoUnavailable);
-                }
-            }
-            _ => return Err(Error::VosoUnavailable),
-        },
-    }
+    let backend = voice_backend();
+    let credentials = backend
+        .create_credentials(channel.id(), &user.id, grants)
+        .await?;
 
-    if let Ok(response) = client
-        .post(&format!(
-            "{}/room/{}/user/{}",
-            *VOSO_URL,
-            channel.id(),
-            user.id
-        ))
-        .header(
-            reqwest::header::AUTHORIZATION,
-            VOSO_MANAGE_TOKEN.to_string(),
-        )
-        .send()
-        .await
-    {
-        response
-            .json()
-            .await
-            .map_err(|_| Error::InvalidOperation)
-            .map(Json)
-    } else {
-        Err(Error::VosoUnavailable)
+    // Voice servers which report participants mark the user as connected once they actually join.
+    if !backend.reports_participants() {
+        voice_connect(&user.id, channel.id()).await;
     }
+
+    Ok(Json(credentials))
 }
@@ -0,0 +1,25 @@
+use chat_core::{
+    models::User, permissions::defn::ChannelPermission, perms, voice_disconnect, voice_fetch_state,
+    Db, EmptyResponse, Error, Ref, Result,
+};
+
+/// # Leave Call
+///
+/// Leave the call in the given channel.
+#[openapi(tag = "Voice")]
+#[post("/<target>/leave_call")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(db, ChannelPermission::ViewChannel)
+        .await?;
+
+    match voice_fetch_state(&user.id).await {
+        Some(state) if state.channel == channel.id() => {
+            voice_disconnect(&user.id).await;
+            Ok(EmptyResponse)
+        }
+        _ => Err(Error::NotInCall),
+    }
+}
@@ -0,0 +1,32 @@
+use chat_core::{
+    models::User, permissions::defn::ChannelPermission, perms, voice::backend::voice_backend,
+    voice_disconnect, voice_fetch_state, Db, EmptyResponse, Error, Ref, Result,
+};
+
+/// # Disconnect Member
+///
+/// Disconnect a member from the call in the given channel.
+#[openapi(tag = "Voice")]
+#[delete("/<target>/voice_state/<member>")]
+pub async fn req(db: &Db, user: User, target: Ref, member: Ref) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+
+    voice_fetch_state(&member.id)
+        .await
+        .filter(|state| state.channel == channel.id())
+        .ok_or(Error::NotInCall)?;
+
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission_and_view_channel(db, ChannelPermission::MoveMembers)
+        .await?;
+
+    permissions.throw_elevated_over(db, &member.id).await?;
+
+    voice_backend()
+        .remove_participant(channel.id(), &member.id)
+        .await?;
+
+    voice_disconnect(&member.id).await;
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,50 @@
+use chat_core::{
+    models::{User, VoiceState},
+    permissions::defn::ChannelPermission,
+    perms, voice_fetch_state, voice_update_state, Db, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct DataEditVoiceState {
+    /// Whether to mute yourself
+    self_mute: Option<bool>,
+    /// Whether to deafen yourself
+    self_deaf: Option<bool>,
+}
+
+/// # Edit Voice State
+///
+/// Mute or deafen yourself in the call in the given channel.
+#[openapi(tag = "Voice")]
+#[patch("/<target>/voice_state", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataEditVoiceState>,
+) -> Result<Json<VoiceState>> {
+    let data = data.into_inner();
+    let channel = target.as_channel(db).await?;
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(db, ChannelPermission::ViewChannel)
+        .await?;
+
+    let mut state = voice_fetch_state(&user.id)
+        .await
+        .filter(|state| state.channel == channel.id())
+        .ok_or(Error::NotInCall)?;
+
+    if let Some(self_mute) = data.self_mute {
+        state.self_mute = self_mute;
+    }
+
+    if let Some(self_deaf) = data.self_deaf {
+        state.self_deaf = self_deaf;
+    }
+
+    voice_update_state(&state).await;
+    Ok(Json(state))
+}
@@ -0,0 +1,137 @@
+use chat_core::{
+    models::{Channel, User, VoiceState},
+    permissions::defn::ChannelPermission,
+    perms,
+    voice::backend::{voice_backend, VoiceGrants},
+    voice_connect, voice_fetch_state, voice_update_state, Db, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct DataModerateVoiceState {
+    /// Whether to mute this member for everyone in the call
+    server_mute: Option<bool>,
+    /// Whether to deafen this member
+    server_deaf: Option<bool>,
+    /// Id of the voice channel to move this member to
+    channel: Option<String>,
+}
+
+/// # Moderate Voice State
+///
+/// Mute, deafen or move a member connected to the call in the given channel.
+///
+/// Moved members are removed from their current call and have to join the destination.
+/// Voice servers which report participants keep the current state until the member leaves.
+#[openapi(tag = "Voice")]
+#[patch("/<target>/voice_state/<member>", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    member: Ref,
+    data: Json<DataModerateVoiceState>,
+) -> Result<Json<VoiceState>> {
+    let data = data.into_inner();
+    let channel = target.as_channel(db).await?;
+
+    let mut state = voice_fetch_state(&member.id)
+        .await
+        .filter(|state| state.channel == channel.id())
+        .ok_or(Error::NotInCall)?;
+
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
+        .throw_permission(db, ChannelPermission::ViewChannel)
+        .await?;
+
+    if data.server_mute.is_some() {
+        permissions
+            .throw_permission(db, ChannelPermission::MuteMembers)
+            .await?;
+    }
+
+    if data.server_deaf.is_some() {
+        permissions
+            .throw_permission(db, ChannelPermission::DeafenMembers)
+            .await?;
+    }
+
+    let destination = match data.channel {
+        Some(id) if id != state.channel => {
+            permissions
+                .throw_permission(db, ChannelPermission::MoveMembers)
+                .await?;
+
+            let destination = Ref::from_unchecked(id).as_channel(db).await?;
+
+            // Members can only be moved between voice channels in the same server.
+            match (&channel, &destination) {
+                (
+                    Channel::VoiceChannel { server, .. },
+                    Channel::VoiceChannel {
+                        server: destination_server,
+                        ..
+                    },
+                ) if server == destination_server => {}
+                _ => return Err(Error::CannotJoinCall),
+            }
+
+            perms(&user)
+                .channel(&destination)
+                .throw_permission_and_view_channel(db, ChannelPermission::MoveMembers)
+                .await?;
+
+            let target_user = member.as_user(db).await?;
+            let mut target_permissions = perms(&target_user).channel(&destination);
+            if !target_permissions
+                .has_permission(db, ChannelPermission::ViewChannel)
+                .await?
+                || !target_permissions
+                    .has_permission(db, ChannelPermission::Connect)
+                    .await?
+            {
+                return Err(Error::CannotJoinCall);
+            }
+
+            Some(destination)
+        }
+        _ => None,
+    };
+
+    permissions.throw_elevated_over(db, &member.id).await?;
+
+    if data.server_mute.is_some() || data.server_deaf.is_some() {
+        if let Some(server_mute) = data.server_mute {
+            state.server_mute = server_mute;
+        }
+
+        if let Some(server_deaf) = data.server_deaf {
+            state.server_deaf = server_deaf;
+        }
+
+        let target_user = member.as_user(db).await?;
+        let grants = VoiceGrants::for_user(db, &target_user, &channel, Some(&state)).await?;
+        voice_backend()
+            .update_participant(&state.channel, &member.id, grants)
+            .await?;
+
+        voice_update_state(&state).await;
+    }
+
+    if let Some(destination) = destination {
+        // Credentials only cover a single call, the member has to join the destination with new ones.
+        let backend = voice_backend();
+        backend
+            .remove_participant(&state.channel, &member.id)
+            .await?;
+
+        // Voice servers which report participants only connect the member once they actually join.
+        if !backend.reports_participants() {
+            state = voice_connect(&member.id, destination.id()).await;
+        }
+    }
+
+    Ok(Json(state))
+}
@@ -39,7 +39,7 @@ pub async fn req(
 
     let avatar = match &data.avatar {
         Some(id) => Some(
-            db.find_and_use_attachment(id, "avatars", "user", &webhook_id)
+            db.find_and_use_attachment(id, "avatars", "user", &webhook_id, Some(&user.id))
                 .await?,
         ),
         None => None,
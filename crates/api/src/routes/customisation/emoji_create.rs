@@ -46,7 +46,7 @@ pub async fn create_emoji(
         }
         EmojiParent::Detached => return Err(Error::InvalidOperation),
     }
-    let attachment = File::use_emoji(db, &id, &id).await?;
+    let attachment = File::use_emoji(db, &id, &id, &user.id).await?;
 
     let emoji = Emoji {
         id,
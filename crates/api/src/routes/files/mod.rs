@@ -0,0 +1,9 @@
+use rocket::Route;
+
+mod serve;
+mod upload;
+
+/// File routes, these are not part of the API specification
+pub fn routes() -> Vec<Route> {
+    routes![upload::req, serve::req]
+}
@@ -0,0 +1,65 @@
+use std::io::Cursor;
+
+use chat_core::{models::File, storage::file_storage, Db, Error, Result};
+use rocket::{
+    response::{self, Responder},
+    Request, Response,
+};
+
+/// Stored file along with its contents
+pub struct FileResponse {
+    file: File,
+    data: Vec<u8>,
+}
+
+impl<'r> Responder<'r, 'static> for FileResponse {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        // Only media is displayed in the browser, everything else is downloaded.
+        let disposition = match self.file.content_type.split('/').next() {
+            Some("image" | "video" | "audio") => "inline",
+            _ => "attachment",
+        };
+
+        let filename: String = self
+            .file
+            .filename
+            .chars()
+            .map(|c| {
+                if c.is_ascii_graphic() && c != '"' && c != '\\' || c == ' ' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        Response::build()
+            .raw_header("Content-Type", self.file.content_type)
+            .raw_header(
+                "Content-Disposition",
+                format!("{disposition}; filename=\"{filename}\""),
+            )
+            .raw_header("X-Content-Type-Options", "nosniff")
+            .raw_header("Cache-Control", "public, max-age=604800, immutable")
+            .sized_body(self.data.len(), Cursor::new(self.data))
+            .ok()
+    }
+}
+
+/// # Fetch File
+///
+/// Download a file by its tag and ID.
+#[get("/<tag>/<id>")]
+pub async fn req(db: &Db, tag: &str, id: &str) -> Result<FileResponse> {
+    let file = db
+        .fetch_attachment(tag, id)
+        .await
+        .map_err(|_| Error::NotFound)?;
+
+    if file.deleted == Some(true) || file.reported == Some(true) {
+        return Err(Error::NotFound);
+    }
+
+    let data = file_storage().get(&file.tag, &file.id).await?;
+    Ok(FileResponse { file, data })
+}
@@ -0,0 +1,48 @@
+use chat_core::{
+    models::{File, User},
+    Db, Error, Result,
+};
+use rocket::{form::Form, fs::TempFile, serde::json::Json, tokio::io::AsyncReadExt};
+use serde::Serialize;
+
+/// # Upload Data
+#[derive(FromForm)]
+pub struct DataUpload<'r> {
+    file: TempFile<'r>,
+}
+
+/// # Upload Response
+#[derive(Serialize)]
+pub struct UploadResponse {
+    /// ID of the uploaded file
+    id: String,
+}
+
+/// # Upload File
+///
+/// Upload a file under the given tag, returning an ID which can then be used
+/// for attachments, avatars, icons and so on.
+#[post("/<tag>", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    tag: &str,
+    data: Form<DataUpload<'_>>,
+) -> Result<Json<UploadResponse>> {
+    let file = &data.file;
+    let filename = file
+        .raw_name()
+        .map(|name| name.dangerous_unsafe_unsanitized_raw().as_str().to_string())
+        .unwrap_or_default();
+
+    let mut buffer = Vec::with_capacity(file.len() as usize);
+    file.open()
+        .await
+        .map_err(|_| Error::InternalError)?
+        .read_to_end(&mut buffer)
+        .await
+        .map_err(|_| Error::InternalError)?;
+
+    let file = File::upload(db, tag, &filename, buffer, &user.id).await?;
+    Ok(Json(UploadResponse { id: file.id }))
+}
@@ -7,14 +7,17 @@ mod admin;
 mod bots;
 mod channels;
 mod customisation;
+mod files;
 mod invites;
 mod onboard;
 mod push;
+mod realtime;
 mod root;
 mod safety;
 mod servers;
 mod sync;
 mod users;
+mod voice;
 mod webhooks;
 
 pub fn mount(mut rocket: Rocket<Build>) -> Rocket<Build> {
@@ -39,6 +42,7 @@ pub fn mount(mut rocket: Rocket<Build>) -> Rocket<Build> {
             "/onboard" => onboard::routes(),
             "/push" => push::routes(),
             "/sync" => sync::routes(),
+            "/realtime" => realtime::routes(),
             "/webhooks" => webhooks::routes()
         };
     } else {
@@ -59,11 +63,15 @@ pub fn mount(mut rocket: Rocket<Build>) -> Rocket<Build> {
             "/auth/mfa" => rocket_authifier::routes::mfa::routes(),
             "/onboard" => onboard::routes(),
             "/push" => push::routes(),
-            "/sync" => sync::routes()
+            "/sync" => sync::routes(),
+            "/realtime" => realtime::routes()
         };
     }
 
     rocket
+        .mount("/realtime", realtime::stream_routes())
+        .mount("/files", files::routes())
+        .mount("/voice", voice::routes())
 }
 
 fn custom_openapi_spec() -> OpenApi {
@@ -119,7 +127,9 @@ fn custom_openapi_spec() -> OpenApi {
             "tags": [
               "Server Information",
               "Server Members",
-              "Server Permissions"
+              "Server Permissions",
+              "Server Integrations",
+              "Server Moderation"
             ]
           },
           {
@@ -154,7 +164,8 @@ fn custom_openapi_spec() -> OpenApi {
             "name": "Miscellaneous",
             "tags": [
               "Sync",
-              "Web Push"
+              "Web Push",
+              "Realtime"
             ]
           }
         ]),
@@ -248,6 +259,16 @@ fn custom_openapi_spec() -> OpenApi {
                 description: Some("Manage permissions for servers".to_owned()),
                 ..Default::default()
             },
+            Tag {
+                name: "Server Integrations".to_owned(),
+                description: Some("Deliver server events to external services".to_owned()),
+                ..Default::default()
+            },
+            Tag {
+                name: "Server Moderation".to_owned(),
+                description: Some("Review reports filed with server moderators".to_owned()),
+                ..Default::default()
+            },
             Tag {
                 name: "Invites".to_owned(),
                 description: Some("View, join and delete invites".to_owned()),
@@ -287,6 +308,13 @@ fn custom_openapi_spec() -> OpenApi {
                 ),
                 ..Default::default()
             },
+            Tag {
+                name: "Realtime".to_owned(),
+                description: Some(
+                    "Receive events over HTTP where websockets are unavailable".to_owned(),
+                ),
+                ..Default::default()
+            },
             Tag {
                 name: "Webhooks".to_owned(),
                 description: Some("Send messages from 3rd party services".to_owned()),
@@ -0,0 +1,136 @@
+use chat_core::{
+    events::{
+        bus::event_bus,
+        client::EventV1,
+        state::{State, SubscriptionStateChange},
+    },
+    models::User,
+    presence_create_session, presence_delete_session,
+    variables::delta::MONO_CHAT_WS_HEARTBEAT_INTERVAL,
+    voice_disconnect, Db, Error, Result,
+};
+use rocket::response::stream::{Event, EventStream};
+use std::time::Duration;
+use ulid::Ulid;
+
+/// Presence session held for as long as the stream is open
+struct StreamSession {
+    state: Option<State>,
+    session_id: u8,
+}
+
+impl Drop for StreamSession {
+    fn drop(&mut self) {
+        if let Some(state) = self.state.take() {
+            let session_id = self.session_id;
+            async_std::task::spawn(async move {
+                let (last_session, presence_change) =
+                    presence_delete_session(&state.cache.user_id, session_id).await;
+
+                if last_session {
+                    voice_disconnect(&state.cache.user_id).await;
+                    state.broadcast_presence_change(false, None).await;
+                } else if presence_change.is_some() {
+                    state.broadcast_presence_change(true, presence_change).await;
+                }
+            });
+        }
+    }
+}
+
+/// # Event Stream
+///
+/// Server-Sent Events fallback for networks which block websockets.
+///
+/// Emits the same events as the websocket, starting with `Authenticated`,
+/// `Session` and `Ready`. The session ID is used to ping this stream and,
+/// with `version=2`, to subscribe to member lists.
+#[get("/events?<ticket>&<version>")]
+pub async fn req(
+    db: &Db,
+    user: Option<User>,
+    ticket: Option<String>,
+    version: Option<i32>,
+) -> Result<EventStream![]> {
+    // EventSource cannot set headers, so a single-use ticket may be passed in the query instead.
+    let user = match (user, ticket) {
+        (Some(user), _) => user,
+        (None, Some(ticket)) => {
+            db.fetch_user(&super::ticket::redeem(&ticket).await?)
+                .await?
+        }
+        (None, None) => return Err(Error::InvalidSession),
+    };
+
+    let db = db.inner().clone();
+    let stream_id = Ulid::new().to_string();
+
+    let mut state = State::from(user);
+    state.lazy = version.unwrap_or(1) >= 2;
+
+    let ready = state.generate_ready_payload(&db).await?;
+    state.insert_subscription(format!("{}!{stream_id}", state.cache.user_id));
+
+    let mut subscription = event_bus().open_subscription().await?;
+
+    let (first_session, session_id, presence_change) =
+        presence_create_session(&state.cache.user_id, 0).await;
+
+    if first_session || presence_change.is_some() {
+        state.broadcast_presence_change(true, presence_change).await;
+    }
+
+    let mut session = StreamSession {
+        state: Some(state),
+        session_id,
+    };
+
+    Ok(EventStream! {
+        let state = session.state.as_mut().expect("state is only taken on drop");
+
+        yield Event::json(&EventV1::Authenticated {
+            heartbeat_interval: *MONO_CHAT_WS_HEARTBEAT_INTERVAL,
+        });
+        yield Event::json(&EventV1::Session { id: stream_id });
+        yield Event::json(&ready);
+
+        loop {
+            match state.apply_state() {
+                SubscriptionStateChange::Reset => {
+                    for id in state.iter_subscriptions() {
+                        subscription.subscribe(id).await;
+                    }
+                }
+                SubscriptionStateChange::Change { add, remove } => {
+                    for id in remove {
+                        subscription.unsubscribe(id).await;
+                    }
+
+                    for id in add {
+                        subscription.subscribe(id).await;
+                    }
+                }
+                SubscriptionStateChange::None => {}
+            }
+
+            match subscription.next().await {
+                Some(EventV1::StreamSubscribe { id }) => {
+                    match state.subscribe_member_list(&db, &id).await {
+                        Ok(event) => yield Event::json(&event),
+                        Err(err) => yield Event::json(&err),
+                    }
+                }
+                Some(EventV1::StreamUnsubscribe { id }) => {
+                    state.unsubscribe_member_list(&id);
+                }
+                Some(mut event) => {
+                    if state.handle_incoming_event_v1(&db, &mut event).await {
+                        yield Event::json(&event);
+                    }
+                }
+                None => break,
+            }
+        }
+    }
+    .heartbeat(Duration::from_secs(*MONO_CHAT_WS_HEARTBEAT_INTERVAL)))
+}
@@ -0,0 +1,24 @@
+use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
+use rocket::Route;
+
+mod events;
+mod ping;
+mod subscribe;
+mod ticket;
+mod typing;
+mod unsubscribe;
+
+pub fn routes() -> (Vec<Route>, OpenApi) {
+    openapi_get_routes_spec![
+        ping::req,
+        ticket::req,
+        subscribe::req,
+        unsubscribe::req,
+        typing::req
+    ]
+}
+
+/// Streaming routes, these are not part of the API specification
+pub fn stream_routes() -> Vec<Route> {
+    routes![events::req]
+}
@@ -0,0 +1,33 @@
+use chat_core::{
+    events::client::{EventV1, Ping},
+    models::User,
+    Result,
+};
+use rocket::serde::json::Json;
+use rocket_empty::EmptyResponse;
+use serde::Deserialize;
+
+/// # Ping Data
+#[derive(Deserialize, JsonSchema)]
+pub struct DataPing {
+    /// Session ID received from the event stream
+    session: String,
+    /// Data echoed back in the `Pong` event
+    data: Ping,
+}
+
+/// # Ping Event Stream
+///
+/// Send a ping which is answered with a `Pong` on the given event stream.
+#[openapi(tag = "Realtime")]
+#[post("/ping", data = "<data>")]
+pub async fn req(user: User, data: Json<DataPing>) -> Result<EmptyResponse> {
+    let data = data.into_inner();
+
+    // Stream topics are scoped to their owner, so a user can only ping their own streams.
+    EventV1::Pong { data: data.data }
+        .p(format!("{}!{}", user.id, data.session))
+        .await;
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,30 @@
+use chat_core::{events::client::EventV1, models::User, Result};
+use rocket::serde::json::Json;
+use rocket_empty::EmptyResponse;
+use serde::Deserialize;
+
+/// # Subscription Data
+#[derive(Deserialize, JsonSchema)]
+pub struct DataSubscribe {
+    /// Session ID received from the event stream
+    session: String,
+    /// Server or channel ID to receive the member list of
+    id: String,
+}
+
+/// # Subscribe to Member List
+///
+/// Request the member list of a server or channel on the given event stream,
+/// which is answered with a `MemberList` event and kept up to date.
+#[openapi(tag = "Realtime")]
+#[post("/subscribe", data = "<data>")]
+pub async fn req(user: User, data: Json<DataSubscribe>) -> Result<EmptyResponse> {
+    let data = data.into_inner();
+
+    // Stream topics are scoped to their owner, so a user can only change their own streams.
+    EventV1::StreamSubscribe { id: data.id }
+        .p(format!("{}!{}", user.id, data.session))
+        .await;
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,51 @@
+use chat_core::{models::User, redis_kiss::get_connection, Error, Result};
+use redis::AsyncCommands;
+use rocket::serde::json::Json;
+use serde::Serialize;
+
+/// Seconds a stream ticket can be redeemed for
+static TICKET_TTL: usize = 30;
+
+fn ticket_key(ticket: &str) -> String {
+    format!("stream_ticket:{ticket}")
+}
+
+/// # Stream Ticket
+#[derive(Serialize, JsonSchema)]
+pub struct StreamTicket {
+    /// Single-use ticket passed to the event stream
+    ticket: String,
+}
+
+/// # Create Stream Ticket
+///
+/// Issue a short-lived, single-use ticket for opening the event stream,
+/// since EventSource cannot set headers.
+#[openapi(tag = "Realtime")]
+#[post("/ticket")]
+pub async fn req(user: User) -> Result<Json<StreamTicket>> {
+    let ticket = nanoid::nanoid!(64);
+    let mut conn = get_connection().await.map_err(|_| Error::InternalError)?;
+
+    conn.set_ex::<_, _, ()>(ticket_key(&ticket), &user.id, TICKET_TTL)
+        .await
+        .map_err(|_| Error::InternalError)?;
+
+    Ok(Json(StreamTicket { ticket }))
+}
+
+/// Redeem a stream ticket, returning the ID of the user it was issued to
+pub async fn redeem(ticket: &str) -> Result<String> {
+    let mut conn = get_connection().await.map_err(|_| Error::InternalError)?;
+
+    let (user_id,): (Option<String>,) = redis::pipe()
+        .atomic()
+        .get(ticket_key(ticket))
+        .del(ticket_key(ticket))
+        .ignore()
+        .query_async(&mut *conn)
+        .await
+        .map_err(|_| Error::InternalError)?;
+
+    user_id.ok_or(Error::InvalidSession)
+}
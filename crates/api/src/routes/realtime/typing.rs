@@ -0,0 +1,48 @@
+use chat_core::{
+    events::client::EventV1, models::User, permissions::defn::ChannelPermission, perms, Db, Ref,
+    Result,
+};
+use rocket::serde::json::Json;
+use rocket_empty::EmptyResponse;
+use serde::Deserialize;
+
+/// # Typing Data
+#[derive(Deserialize, JsonSchema)]
+pub struct DataTyping {
+    /// Channel being typed in
+    channel: String,
+    /// Whether the user started or stopped typing
+    typing: bool,
+}
+
+/// # Send Typing
+///
+/// Indicate that you started or stopped typing in a channel, for clients using the event stream.
+#[openapi(tag = "Realtime")]
+#[post("/typing", data = "<data>")]
+pub async fn req(db: &Db, user: User, data: Json<DataTyping>) -> Result<EmptyResponse> {
+    let data = data.into_inner();
+    let channel = Ref::from_unchecked(data.channel).as_channel(db).await?;
+
+    perms(&user)
+        .channel(&channel)
+        .throw_permission(db, ChannelPermission::ViewChannel)
+        .await?;
+
+    let id = channel.id().to_string();
+    if data.typing {
+        EventV1::ChannelStartTyping {
+            id: id.clone(),
+            user: user.id,
+        }
+    } else {
+        EventV1::ChannelStopTyping {
+            id: id.clone(),
+            user: user.id,
+        }
+    }
+    .p(id)
+    .await;
+
+    Ok(EmptyResponse)
+}
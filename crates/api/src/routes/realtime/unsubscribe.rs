@@ -0,0 +1,28 @@
+use chat_core::{events::client::EventV1, models::User, Result};
+use rocket::serde::json::Json;
+use rocket_empty::EmptyResponse;
+use serde::Deserialize;
+
+/// # Unsubscription Data
+#[derive(Deserialize, JsonSchema)]
+pub struct DataUnsubscribe {
+    /// Session ID received from the event stream
+    session: String,
+    /// Server or channel ID to stop receiving the member list of
+    id: String,
+}
+
+/// # Unsubscribe from Member List
+///
+/// Stop receiving member list updates for a server or channel on the given event stream.
+#[openapi(tag = "Realtime")]
+#[post("/unsubscribe", data = "<data>")]
+pub async fn req(user: User, data: Json<DataUnsubscribe>) -> Result<EmptyResponse> {
+    let data = data.into_inner();
+
+    EventV1::StreamUnsubscribe { id: data.id }
+        .p(format!("{}!{}", user.id, data.session))
+        .await;
+
+    Ok(EmptyResponse)
+}
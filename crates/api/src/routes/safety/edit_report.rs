@@ -0,0 +1,85 @@
+use chat_core::{
+    models::{
+        admin_action::{AdminAction, AdminActionType},
+        report::{FieldsReport, PartialReport, ReportStatus},
+        Report, User,
+    },
+    Db, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Report Data
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataEditReport {
+    /// New status of the report
+    status: Option<ReportStatus>,
+
+    /// Moderator to assign this report to
+    assigned_moderator_id: Option<String>,
+
+    /// Moderator notes
+    #[validate(length(min = 0, max = 10000))]
+    notes: Option<String>,
+
+    /// Fields to remove from the report
+    #[serde(default)]
+    remove: Vec<FieldsReport>,
+}
+
+/// # Edit Report
+///
+/// Assign, annotate, resolve or reject a report.
+/// The reporter is notified whenever the status changes.
+#[openapi(tag = "User Safety")]
+#[patch("/reports/<target>", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataEditReport>,
+) -> Result<Json<Report>> {
+    user.throw_if_not_privileged()?;
+
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if let Some(ReportStatus::Rejected { rejection_reason }) = &data.status {
+        if rejection_reason.trim().is_empty() {
+            return Err(Error::InvalidProperty);
+        }
+    }
+
+    if let Some(moderator_id) = &data.assigned_moderator_id {
+        if !db.fetch_user(moderator_id).await?.privileged {
+            return Err(Error::InvalidOperation);
+        }
+    }
+
+    let mut report = db.fetch_report(&target.id).await?;
+    report
+        .update(
+            db,
+            PartialReport {
+                status: data.status,
+                assigned_moderator_id: data.assigned_moderator_id,
+                notes: data.notes,
+                ..Default::default()
+            },
+            data.remove,
+        )
+        .await?;
+
+    AdminAction::create(
+        db,
+        &user.id,
+        Some(&report.id),
+        AdminActionType::EditReport,
+        None,
+    )
+    .await?;
+
+    Ok(Json(report))
+}
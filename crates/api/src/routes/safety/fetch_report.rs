@@ -0,0 +1,15 @@
+use chat_core::{
+    models::{Report, User},
+    Db, Ref, Result,
+};
+use rocket::serde::json::Json;
+
+/// # Fetch Report
+///
+/// Fetch a report by its ID.
+#[openapi(tag = "User Safety")]
+#[get("/reports/<target>")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Report>> {
+    user.throw_if_not_privileged()?;
+    db.fetch_report(&target.id).await.map(Json)
+}
@@ -0,0 +1,47 @@
+use chat_core::{
+    models::{
+        report::{ReportQuery, ReportStatusString, ReportedContentType},
+        Report, User,
+    },
+    Db, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+
+/// # Query Options
+#[derive(Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsFetchReports {
+    /// Only include reports with this status
+    status: Option<ReportStatusString>,
+
+    /// Only include reports against this type of content
+    content_type: Option<ReportedContentType>,
+
+    /// Only include reports assigned to this moderator
+    assigned_moderator_id: Option<String>,
+
+    /// Only include reports created before this ID
+    before: Option<String>,
+
+    /// Maximum number of reports to return (1-100)
+    limit: Option<i64>,
+}
+
+/// # Fetch Reports
+///
+/// Page through reports, newest first.
+#[openapi(tag = "User Safety")]
+#[get("/reports?<options..>")]
+pub async fn req(db: &Db, user: User, options: OptionsFetchReports) -> Result<Json<Vec<Report>>> {
+    user.throw_if_not_privileged()?;
+
+    db.fetch_reports(ReportQuery {
+        status: options.status,
+        content_type: options.content_type,
+        assigned_moderator_id: options.assigned_moderator_id,
+        before: options.before,
+        limit: Some(options.limit.unwrap_or(50).clamp(1, 100)),
+    })
+    .await
+    .map(Json)
+}
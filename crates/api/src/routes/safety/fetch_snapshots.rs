@@ -0,0 +1,25 @@
+use chat_core::{
+    models::{snapshot::SnapshotWithContext, User},
+    Db, Ref, Result,
+};
+use futures::future::try_join_all;
+use rocket::serde::json::Json;
+
+/// # Fetch Snapshots
+///
+/// Fetch the content captured when a report was filed,
+/// along with the users, channels and server it references.
+#[openapi(tag = "User Safety")]
+#[get("/snapshot/<target>")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Vec<SnapshotWithContext>>> {
+    user.throw_if_not_privileged()?;
+
+    let snapshots = db.fetch_snapshots(&target.id).await?;
+    try_join_all(
+        snapshots
+            .into_iter()
+            .map(|snapshot| snapshot.with_context(db)),
+    )
+    .await
+    .map(Json)
+}
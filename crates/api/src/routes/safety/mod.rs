@@ -1,8 +1,18 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod edit_report;
+mod fetch_report;
+mod fetch_reports;
+mod fetch_snapshots;
 mod report_content;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
-    openapi_get_routes_spec![report_content::report_content]
+    openapi_get_routes_spec![
+        report_content::report_content,
+        fetch_reports::req,
+        fetch_report::req,
+        edit_report::req,
+        fetch_snapshots::req
+    ]
 }
@@ -102,6 +102,7 @@ pub async fn report_content(db: &Db, user: User, data: Json<DataReportContent>)
         additional_context: data.additional_context,
         status: ReportStatus::Created {},
         notes: String::new(),
+        assigned_moderator_id: None,
     };
 
     db.insert_report(&report).await?;
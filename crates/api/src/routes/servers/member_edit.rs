@@ -93,6 +93,10 @@ pub async fn req(
         required.push(ChannelPermission::TimeoutMembers);
     }
 
+    if let Some(timeout) = &data.timeout {
+        Member::validate_timeout(timeout)?;
+    }
+
     for permission in required {
         permissions.throw_permission(db, permission).await?;
     }
@@ -151,7 +155,7 @@ pub async fn req(
 
     // 2. Apply new avatar
     if let Some(avatar) = avatar {
-        partial.avatar = Some(File::use_avatar(db, &avatar, &user.id).await?);
+        partial.avatar = Some(File::use_avatar(db, &avatar, &user.id, &user.id).await?);
     }
 
     member
@@ -12,8 +12,18 @@ mod member_experimental_query;
 mod member_fetch;
 mod member_fetch_all;
 mod member_remove;
+mod outbound_webhook_create;
+mod outbound_webhook_delete;
+mod outbound_webhook_deliveries;
+mod outbound_webhook_edit;
+mod outbound_webhook_fetch_all;
+mod outbound_webhook_test;
 mod permissions_set;
 mod permissions_set_default;
+mod report_action;
+mod report_create;
+mod report_list;
+mod report_snapshots;
 mod roles_create;
 mod roles_delete;
 mod roles_edit;
@@ -45,6 +55,16 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         roles_delete::req,
         permissions_set::req,
         permissions_set_default::req,
-        emoji_list::list_emoji
+        emoji_list::list_emoji,
+        outbound_webhook_create::req,
+        outbound_webhook_fetch_all::req,
+        outbound_webhook_edit::req,
+        outbound_webhook_delete::req,
+        outbound_webhook_deliveries::req,
+        outbound_webhook_test::req,
+        report_create::req,
+        report_list::req,
+        report_snapshots::req,
+        report_action::req
     ]
 }
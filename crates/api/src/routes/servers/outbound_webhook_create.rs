@@ -0,0 +1,71 @@
+use chat_core::{
+    models::{
+        outbound_webhook::{DataCreateOutboundWebhook, OutboundWebhook},
+        User,
+    },
+    permissions::defn::ChannelPermission,
+    perms,
+    util::guard::resolve_public_url,
+    variables::delta::MONO_CHAT_MAX_OUTBOUND_WEBHOOKS,
+    Db, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Outbound Webhook
+///
+/// Subscribe an external URL to events from this server.
+/// The signing secret is only returned in this response.
+#[openapi(tag = "Server Integrations")]
+#[post("/<target>/outbound_webhooks", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataCreateOutboundWebhook>,
+) -> Result<Json<OutboundWebhook>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, ChannelPermission::ManageWebhooks)
+        .await?;
+
+    if let Some(channel_id) = &data.channel_id {
+        if !server.channels.contains(channel_id) {
+            return Err(Error::UnknownChannel);
+        }
+    }
+
+    resolve_public_url(&data.url).await?;
+
+    if db
+        .fetch_outbound_webhooks_for_server(&server.id)
+        .await?
+        .len()
+        >= *MONO_CHAT_MAX_OUTBOUND_WEBHOOKS
+    {
+        return Err(Error::TooManyOutboundWebhooks {
+            max: *MONO_CHAT_MAX_OUTBOUND_WEBHOOKS,
+        });
+    }
+
+    let webhook = OutboundWebhook {
+        id: Ulid::new().to_string(),
+        server_id: server.id,
+        channel_id: data.channel_id,
+        creator_id: user.id,
+        url: data.url,
+        events: data.events,
+        secret: Some(nanoid::nanoid!(64)),
+        enabled: true,
+    };
+
+    webhook.create(db).await?;
+
+    Ok(Json(webhook))
+}
@@ -0,0 +1,23 @@
+use chat_core::{
+    models::User, permissions::defn::ChannelPermission, perms, Db, EmptyResponse, Ref, Result,
+};
+
+/// # Delete Outbound Webhook
+///
+/// Delete an outbound webhook and its delivery log.
+#[openapi(tag = "Server Integrations")]
+#[delete("/<target>/outbound_webhooks/<webhook_id>")]
+pub async fn req(db: &Db, user: User, target: Ref, webhook_id: Ref) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, ChannelPermission::ManageWebhooks)
+        .await?;
+
+    webhook_id
+        .as_outbound_webhook_in(db, &server.id)
+        .await?
+        .delete(db)
+        .await
+        .map(|_| EmptyResponse)
+}
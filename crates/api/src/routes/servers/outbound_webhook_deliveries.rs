@@ -0,0 +1,30 @@
+use chat_core::{
+    models::{outbound_webhook::OutboundWebhookDelivery, User},
+    permissions::defn::ChannelPermission,
+    perms, Db, Ref, Result,
+};
+use rocket::serde::json::Json;
+
+/// # Fetch Deliveries
+///
+/// Fetch the most recent delivery attempts for an outbound webhook.
+#[openapi(tag = "Server Integrations")]
+#[get("/<target>/outbound_webhooks/<webhook_id>/deliveries")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    webhook_id: Ref,
+) -> Result<Json<Vec<OutboundWebhookDelivery>>> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, ChannelPermission::ManageWebhooks)
+        .await?;
+
+    let webhook = webhook_id.as_outbound_webhook_in(db, &server.id).await?;
+
+    db.fetch_outbound_webhook_deliveries(&webhook.id, 50)
+        .await
+        .map(Json)
+}
@@ -0,0 +1,77 @@
+use chat_core::{
+    models::{
+        outbound_webhook::{DataEditOutboundWebhook, OutboundWebhook, PartialOutboundWebhook},
+        User,
+    },
+    permissions::defn::ChannelPermission,
+    perms,
+    util::guard::resolve_public_url,
+    Db, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use validator::Validate;
+
+/// # Edit Outbound Webhook
+///
+/// Edit the URL, events or scope of an outbound webhook.
+#[openapi(tag = "Server Integrations")]
+#[patch("/<target>/outbound_webhooks/<webhook_id>", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    webhook_id: Ref,
+    data: Json<DataEditOutboundWebhook>,
+) -> Result<Json<OutboundWebhook>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, ChannelPermission::ManageWebhooks)
+        .await?;
+
+    let mut webhook = webhook_id.as_outbound_webhook_in(db, &server.id).await?;
+
+    if data.url.is_none()
+        && data.events.is_none()
+        && data.channel_id.is_none()
+        && data.enabled.is_none()
+        && data.remove.is_empty()
+    {
+        return Ok(Json(webhook));
+    }
+
+    if let Some(channel_id) = &data.channel_id {
+        if !server.channels.contains(channel_id) {
+            return Err(Error::UnknownChannel);
+        }
+    }
+
+    if let Some(url) = &data.url {
+        resolve_public_url(url).await?;
+    }
+
+    let DataEditOutboundWebhook {
+        url,
+        events,
+        channel_id,
+        enabled,
+        remove,
+    } = data;
+
+    let partial = PartialOutboundWebhook {
+        url,
+        events,
+        channel_id,
+        enabled,
+        ..Default::default()
+    };
+
+    webhook.update(db, partial, remove).await?;
+    webhook.secret = None;
+
+    Ok(Json(webhook))
+}
@@ -0,0 +1,31 @@
+use chat_core::{
+    models::{OutboundWebhook, User},
+    permissions::defn::ChannelPermission,
+    perms, Db, Ref, Result,
+};
+use rocket::serde::json::Json;
+
+/// # Fetch Outbound Webhooks
+///
+/// Fetch all outbound webhooks registered on this server.
+#[openapi(tag = "Server Integrations")]
+#[get("/<target>/outbound_webhooks")]
+pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Vec<OutboundWebhook>>> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, ChannelPermission::ManageWebhooks)
+        .await?;
+
+    Ok(Json(
+        db.fetch_outbound_webhooks_for_server(&server.id)
+            .await?
+            .into_iter()
+            .map(|mut webhook| {
+                // Avoid leaking the signing secret
+                webhook.secret = None;
+                webhook
+            })
+            .collect(),
+    ))
+}
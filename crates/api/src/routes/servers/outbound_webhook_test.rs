@@ -0,0 +1,32 @@
+use chat_core::{
+    models::{outbound_webhook::OutboundWebhookDelivery, User},
+    permissions::defn::ChannelPermission,
+    perms, tasks, Db, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+
+/// # Test Outbound Webhook
+///
+/// Send a test delivery to an outbound webhook and report the outcome.
+/// Test deliveries are not retried.
+#[openapi(tag = "Server Integrations")]
+#[post("/<target>/outbound_webhooks/<webhook_id>/test")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    webhook_id: Ref,
+) -> Result<Json<OutboundWebhookDelivery>> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, ChannelPermission::ManageWebhooks)
+        .await?;
+
+    let webhook = webhook_id.as_outbound_webhook_in(db, &server.id).await?;
+    let event_type = *webhook.events.first().ok_or(Error::InvalidOperation)?;
+
+    tasks::outbound_webhooks::deliver(db, &webhook, event_type, None, 1)
+        .await
+        .map(Json)
+}
@@ -0,0 +1,148 @@
+use chat_core::{
+    models::{
+        server_member::{MemberCompositeKey, PartialMember, RemovalIntention},
+        server_report::{ServerReportAction, ServerReportStatus, ServerReportedContent},
+        Member, ServerReport, User,
+    },
+    permissions::defn::ChannelPermission,
+    perms, Db, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+
+/// # Report Action
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct DataServerReportAction {
+    /// Action to take against the reported content
+    action: ServerReportAction,
+}
+
+/// # Act on Server Report
+///
+/// Dismiss a report, or act on it by deleting the message
+/// or timing out, kicking or banning the reported member.
+#[openapi(tag = "Server Moderation")]
+#[post("/<target>/reports/<report>/action", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    report: Ref,
+    data: Json<DataServerReportAction>,
+) -> Result<Json<ServerReport>> {
+    let action = data.into_inner().action;
+
+    let server = target.as_server(db).await?;
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(db, ChannelPermission::ManageReports)
+        .await?;
+
+    let mut report = db.fetch_server_report(&server.id, &report.id).await?;
+    if report.status != ServerReportStatus::Open {
+        return Err(Error::InvalidOperation);
+    }
+
+    match &action {
+        ServerReportAction::Dismiss => {}
+        ServerReportAction::DeleteMessage => {
+            let message_id = match &report.content {
+                ServerReportedContent::Message { id }
+                | ServerReportedContent::Member {
+                    message_id: Some(id),
+                    ..
+                } => id,
+                _ => return Err(Error::InvalidOperation),
+            };
+
+            let message = db.fetch_message(message_id).await?;
+            perms(&user)
+                .channel(&db.fetch_channel(&message.channel).await?)
+                .throw_permission(db, ChannelPermission::ManageMessages)
+                .await?;
+
+            message.delete(db).await?;
+        }
+        ServerReportAction::Timeout { .. }
+        | ServerReportAction::Kick
+        | ServerReportAction::Ban { .. } => {
+            if report.reported_user_id == user.id {
+                return Err(Error::CannotRemoveYourself);
+            }
+
+            if let ServerReportAction::Timeout { until } = &action {
+                Member::validate_timeout(until)?;
+            }
+
+            if report.reported_user_id == server.owner {
+                return Err(Error::InvalidOperation);
+            }
+
+            permissions
+                .throw_permission(
+                    db,
+                    match &action {
+                        ServerReportAction::Timeout { .. } => ChannelPermission::TimeoutMembers,
+                        ServerReportAction::Kick => ChannelPermission::KickMembers,
+                        _ => ChannelPermission::BanMembers,
+                    },
+                )
+                .await?;
+
+            let member = db
+                .fetch_member(&server.id, &report.reported_user_id)
+                .await
+                .ok();
+
+            // Check privileges against the member if they are still present
+            if member.is_some() {
+                permissions
+                    .throw_elevated_over(db, &report.reported_user_id)
+                    .await?;
+            }
+
+            match (&action, member) {
+                (ServerReportAction::Timeout { until }, Some(mut member)) => {
+                    member
+                        .update(
+                            db,
+                            PartialMember {
+                                timeout: Some(until.clone()),
+                                ..Default::default()
+                            },
+                            vec![],
+                        )
+                        .await?;
+                }
+                (ServerReportAction::Kick, Some(member)) => {
+                    server
+                        .remove_member(db, member, RemovalIntention::Kick, false)
+                        .await?;
+                }
+                (ServerReportAction::Ban { reason }, Some(member)) => {
+                    server
+                        .clone()
+                        .ban_member(db, member, reason.clone())
+                        .await?;
+                }
+                (ServerReportAction::Ban { reason }, None) => {
+                    server
+                        .clone()
+                        .ban_user(
+                            db,
+                            MemberCompositeKey {
+                                server: server.id.to_string(),
+                                user: report.reported_user_id.to_string(),
+                            },
+                            reason.clone(),
+                        )
+                        .await?;
+                }
+                _ => return Err(Error::NotFound),
+            }
+        }
+    }
+
+    report.close(db, &user.id, action).await?;
+    Ok(Json(report))
+}
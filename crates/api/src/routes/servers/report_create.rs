@@ -0,0 +1,31 @@
+use chat_core::{
+    models::{server_report::DataCreateServerReport, ServerReport, User},
+    Db, Error, Ref, Result,
+};
+use rocket::serde::json::Json;
+use validator::Validate;
+
+/// # Report to Server Moderators
+///
+/// Report a message or member to this server's moderators.
+#[openapi(tag = "Server Moderation")]
+#[post("/<target>/reports", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataCreateServerReport>,
+) -> Result<Json<ServerReport>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if user.bot.is_some() {
+        return Err(Error::IsBot);
+    }
+
+    let server = target.as_server(db).await?;
+    ServerReport::create(db, &server, &user, data)
+        .await
+        .map(Json)
+}
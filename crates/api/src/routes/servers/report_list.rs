@@ -0,0 +1,47 @@
+use chat_core::{
+    models::{server_report::ServerReportStatus, ServerReport, User},
+    permissions::defn::ChannelPermission,
+    perms, Db, Ref, Result,
+};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+
+/// # Query Options
+#[derive(Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsReportList {
+    /// Only include reports with this status
+    status: Option<ServerReportStatus>,
+
+    /// Only include reports created before this ID
+    before: Option<String>,
+
+    /// Maximum number of reports to return (1-100)
+    limit: Option<i64>,
+}
+
+/// # Fetch Server Reports
+///
+/// Page through reports filed with this server's moderators, newest first.
+#[openapi(tag = "Server Moderation")]
+#[get("/<target>/reports?<options..>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    options: OptionsReportList,
+) -> Result<Json<Vec<ServerReport>>> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, ChannelPermission::ManageReports)
+        .await?;
+
+    db.fetch_server_reports(
+        &server.id,
+        options.status,
+        options.before.as_deref(),
+        options.limit.unwrap_or(50).clamp(1, 100),
+    )
+    .await
+    .map(Json)
+}
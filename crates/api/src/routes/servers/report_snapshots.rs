@@ -0,0 +1,36 @@
+use chat_core::{
+    models::{snapshot::SnapshotWithContext, User},
+    permissions::defn::ChannelPermission,
+    perms, Db, Ref, Result,
+};
+use futures::future::try_join_all;
+use rocket::serde::json::Json;
+
+/// # Fetch Server Report Snapshots
+///
+/// Fetch the content captured when a report was filed.
+#[openapi(tag = "Server Moderation")]
+#[get("/<target>/reports/<report>/snapshots")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    report: Ref,
+) -> Result<Json<Vec<SnapshotWithContext>>> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, ChannelPermission::ManageReports)
+        .await?;
+
+    let report = db.fetch_server_report(&server.id, &report.id).await?;
+    let snapshots = db.fetch_snapshots(&report.id).await?;
+
+    try_join_all(
+        snapshots
+            .into_iter()
+            .map(|snapshot| snapshot.with_context(db)),
+    )
+    .await
+    .map(Json)
+}
@@ -1,5 +1,6 @@
 use chat_core::{
     models::{
+        ratelimit_events::ratelimit::{RatelimitEvent, RatelimitEventType},
         server::{CreateServerLegacyResponse, DataCreateServer},
         Member, Server, User,
     },
@@ -27,8 +28,10 @@ pub async fn create_server(
         .map_err(|error| Error::FailedValidation { error });
 
     user.can_acquire_server(db).await?;
+    RatelimitEvent::throw_if_on_cooldown(db, &user.id, RatelimitEventType::ServerCreate).await?;
 
     let (server, channels) = Server::create(db, data, &user, true).await?;
+    RatelimitEvent::create(db, user.id.to_string(), RatelimitEventType::ServerCreate).await?;
     let channels = Member::create(db, &server, &user, Some(channels)).await?;
 
     Ok(Json(CreateServerLegacyResponse {
@@ -154,12 +154,12 @@ pub async fn req(
     }
 
     if let Some(icon) = icon {
-        partial.icon = Some(File::use_server_icon(db, &icon, &server.id).await?);
+        partial.icon = Some(File::use_server_icon(db, &icon, &server.id, &user.id).await?);
         server.icon = partial.icon.clone();
     }
 
     if let Some(banner) = banner {
-        partial.banner = Some(File::use_banner(db, &banner, &server.id).await?);
+        partial.banner = Some(File::use_banner(db, &banner, &server.id, &user.id).await?);
         server.banner = partial.banner.clone();
     }
 
@@ -1,4 +1,11 @@
-use chat_core::{authifier::models::Account, models::User, Database, Error, Result};
+use chat_core::{
+    authifier::models::Account,
+    models::{
+        ratelimit_events::ratelimit::{RatelimitEvent, RatelimitEventType},
+        User,
+    },
+    Database, Error, Result,
+};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rocket::{serde::json::Json, State};
@@ -33,6 +40,9 @@ pub async fn req(
         .verify_password(&data.password)
         .map_err(|_| Error::InvalidCredentials)?;
 
+    RatelimitEvent::throw_if_on_cooldown(db, &user.id, RatelimitEventType::UsernameChange).await?;
+
     user.update_username(db, data.username).await?;
+    RatelimitEvent::create(db, user.id.to_string(), RatelimitEventType::UsernameChange).await?;
     Ok(Json(user.foreign()))
 }
@@ -1,8 +1,10 @@
 use chat_core::{
+    events::client::EventV1,
     models::{
         user::{FieldsUser, PartialUser, UserStatus},
         File, User,
     },
+    presence_filter_effective,
     util::r#ref::Ref,
     Database, Error, Result,
 };
@@ -110,10 +112,13 @@ pub async fn req(
     };
 
     if let Some(avatar) = data.avatar {
-        partial.avatar = Some(File::use_avatar(db, &avatar, &user.id).await?);
+        partial.avatar = Some(File::use_avatar(db, &avatar, &user.id, &user.id).await?);
     }
 
+    let mut chosen_presence = None;
     if let Some(status) = data.status {
+        chosen_presence = status.presence.clone();
+
         let mut new_status = user.status.take().unwrap_or_default();
         if let Some(text) = status.text {
             new_status.text = Some(text);
@@ -123,6 +128,18 @@ pub async fn req(
             new_status.presence = Some(presence);
         }
 
+        if let Some(emoji) = status.emoji {
+            new_status.emoji = Some(emoji);
+        }
+
+        if let Some(activity) = status.activity {
+            new_status.activity = Some(activity);
+        }
+
+        if let Some(expires_at) = status.expires_at {
+            new_status.expires_at = Some(expires_at);
+        }
+
         partial.status = Some(new_status);
     }
 
@@ -133,7 +150,8 @@ pub async fn req(
         }
 
         if let Some(background) = profile.background {
-            new_profile.background = Some(File::use_background(db, &background, &user.id).await?);
+            new_profile.background =
+                Some(File::use_background(db, &background, &user.id, &user.id).await?);
         }
 
         partial.profile = Some(new_profile);
@@ -142,5 +160,24 @@ pub async fn req(
     user.update(db, partial, data.remove.unwrap_or_default())
         .await?;
 
+    // The presence shown while online follows the newly chosen presence.
+    if let Some(presence) = chosen_presence {
+        if let Some(session_presence) = presence_filter_effective(&[user.id.clone()])
+            .await
+            .remove(&user.id)
+        {
+            EventV1::UserUpdate {
+                id: user.id.clone(),
+                data: PartialUser {
+                    online_presence: Some(session_presence.resolve(Some(&presence))),
+                    ..Default::default()
+                },
+                clear: vec![],
+            }
+            .p_user(user.id.clone(), db)
+            .await;
+        }
+    }
+
     Ok(Json(user.foreign()))
 }
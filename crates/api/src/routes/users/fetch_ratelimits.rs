@@ -0,0 +1,19 @@
+use chat_core::{models::User, Db, Result};
+use rocket::serde::json::Json;
+
+use crate::util::ratelimiter::{BucketState, RatelimitIdentity};
+
+/// # Fetch Ratelimits
+///
+/// Fetch the state of your ratelimit buckets.
+/// Buckets tracked per channel, server or user are only included if `resource` is given.
+#[openapi(tag = "User Information")]
+#[get("/@me/ratelimits?<resource>")]
+pub async fn req(
+    db: &Db,
+    _user: User,
+    identity: RatelimitIdentity,
+    resource: Option<String>,
+) -> Result<Json<Vec<BucketState>>> {
+    Ok(Json(identity.bucket_states(db, resource.as_deref()).await))
+}
@@ -7,6 +7,7 @@ mod change_username;
 mod edit_user;
 mod fetch_dms;
 mod fetch_profile;
+mod fetch_ratelimits;
 mod fetch_self;
 mod fetch_user;
 mod fetch_user_flags;
@@ -25,6 +26,7 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         edit_user::req,
         fetch_dms::req,
         fetch_profile::req,
+        fetch_ratelimits::req,
         fetch_self::req,
         fetch_user::req,
         fetch_user_flags::fetch_user_flags,
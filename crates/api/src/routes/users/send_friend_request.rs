@@ -1,4 +1,7 @@
-use chat_core::models::User;
+use chat_core::models::{
+    ratelimit_events::ratelimit::{RatelimitEvent, RatelimitEventType},
+    User,
+};
 use chat_core::{Database, Error, Result};
 
 use rocket::serde::json::Json;
@@ -24,7 +27,11 @@ pub async fn req(
             return Err(Error::IsBot);
         }
 
+        RatelimitEvent::throw_if_on_cooldown(db, &user.id, RatelimitEventType::FriendRequest)
+            .await?;
+
         user.add_friend(db, &mut target).await?;
+        RatelimitEvent::create(db, user.id.to_string(), RatelimitEventType::FriendRequest).await?;
         Ok(Json(target.with_auto_perspective(db, &user).await))
     } else {
         Err(Error::InvalidProperty)
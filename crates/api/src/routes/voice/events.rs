@@ -0,0 +1,55 @@
+use chat_core::{
+    voice::backend::{voice_backend, ParticipantEvent},
+    voice_connect, voice_disconnect, voice_fetch_state, EmptyResponse, Error, Result,
+};
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+    Request,
+};
+
+/// Signature sent by the voice server in the `Authorization` header
+pub struct VoiceServerAuthorization(String);
+
+#[async_trait]
+impl<'r> FromRequest<'r> for VoiceServerAuthorization {
+    type Error = Error;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.headers().get_one("Authorization") {
+            Some(value) => Outcome::Success(VoiceServerAuthorization(value.to_string())),
+            None => Outcome::Failure((Status::Unauthorized, Error::InvalidCredentials)),
+        }
+    }
+}
+
+/// # Voice Server Events
+///
+/// Receives participants joining and leaving calls from voice servers which report them.
+#[post("/events", data = "<body>")]
+pub async fn req(authorization: VoiceServerAuthorization, body: Vec<u8>) -> Result<EmptyResponse> {
+    match voice_backend().parse_event(&authorization.0, &body)? {
+        Some(ParticipantEvent::Joined {
+            channel_id,
+            user_id,
+        }) => {
+            // Credentials are only issued to users who may connect to the room.
+            voice_connect(&user_id, &channel_id).await;
+        }
+        Some(ParticipantEvent::Left {
+            channel_id,
+            user_id,
+        }) => {
+            // Leaving may be reported after the user already joined another call.
+            if voice_fetch_state(&user_id)
+                .await
+                .is_some_and(|state| state.channel == channel_id)
+            {
+                voice_disconnect(&user_id).await;
+            }
+        }
+        None => {}
+    }
+
+    Ok(EmptyResponse)
+}
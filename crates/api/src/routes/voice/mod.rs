@@ -0,0 +1,8 @@
+use rocket::Route;
+
+mod events;
+
+/// Voice server routes, these are not part of the API specification
+pub fn routes() -> Vec<Route> {
+    routes![events::req]
+}
@@ -6,6 +6,8 @@ mod webhook_delete_token;
 mod webhook_edit;
 mod webhook_edit_token;
 mod webhook_execute;
+mod webhook_execute_github;
+mod webhook_execute_slack;
 mod webhook_fetch;
 mod webhook_fetch_token;
 
@@ -16,6 +18,8 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         webhook_edit::webhook_edit,
         webhook_edit_token::webhook_edit_token,
         webhook_execute::webhook_execute,
+        webhook_execute_github::webhook_execute_github,
+        webhook_execute_slack::webhook_execute_slack,
         webhook_fetch::webhook_fetch,
         webhook_fetch_token::webhook_fetch_token
     ]
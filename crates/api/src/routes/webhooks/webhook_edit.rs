@@ -48,7 +48,7 @@ pub async fn webhook_edit(
 
     if let Some(avatar) = avatar {
         let file = db
-            .find_and_use_attachment(&avatar, "avatars", "user", &webhook.id)
+            .find_and_use_attachment(&avatar, "avatars", "user", &webhook.id, Some(&user.id))
             .await?;
 
         partial.avatar = Some(file)
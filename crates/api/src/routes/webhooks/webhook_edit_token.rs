@@ -39,7 +39,7 @@ pub async fn webhook_edit_token(
 
     if let Some(avatar) = avatar {
         let file = db
-            .find_and_use_attachment(&avatar, "avatars", "user", &webhook.id)
+            .find_and_use_attachment(&avatar, "avatars", "user", &webhook.id, None)
             .await?;
 
         partial.avatar = Some(file)
@@ -3,7 +3,6 @@ use chat_core::{
         message::{DataMessageSend, MessageAuthor},
         Message,
     },
-    permissions::{defn::ChannelPermission, r#impl::PermissionValue},
     util::{idempotency::IdempotencyKey, reference::Reference},
     Database,
 };
@@ -29,24 +28,7 @@ pub async fn webhook_execute(
     let webhook = webhook_id.as_webhook(db).await?;
     webhook.assert_token(&token)?;
 
-    let permissions: PermissionValue = webhook.permissions.into();
-    permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
-
-    if data.attachments.as_ref().map_or(false, |v| !v.is_empty()) {
-        permissions.throw_if_lacking_channel_permission(ChannelPermission::UploadFiles)?;
-    }
-
-    if data.embeds.as_ref().map_or(false, |v| !v.is_empty()) {
-        permissions.throw_if_lacking_channel_permission(ChannelPermission::SendEmbeds)?;
-    }
-
-    if data.masquerade.is_some() {
-        permissions.throw_if_lacking_channel_permission(ChannelPermission::Masquerade)?;
-    }
-
-    if data.interactions.is_some() {
-        permissions.throw_if_lacking_channel_permission(ChannelPermission::React)?;
-    }
+    webhook.throw_if_cannot_send(&data)?;
 
     let channel = db.fetch_channel(&webhook.channel_id).await?;
 
@@ -0,0 +1,52 @@
+use chat_core::{
+    models::message::MessageAuthor,
+    models::Message,
+    util::{idempotency::IdempotencyKey, reference::Reference},
+    Database, EmptyResponse, Error, Result,
+};
+use rocket::{serde::json::Json, State};
+use serde_json::Value;
+use validator::Validate;
+
+use crate::util::webhook_formats::{github_to_message, GitHubEvent};
+
+/// # Executes a webhook with a GitHub payload
+///
+/// Accepts GitHub webhook deliveries sent as `application/json`.
+/// Pushes, pull requests, issues, comments, releases and stars are shown as embeds.
+#[openapi(tag = "Webhooks")]
+#[post("/<webhook_id>/<token>/github", data = "<data>")]
+pub async fn webhook_execute_github(
+    db: &State<Database>,
+    webhook_id: Reference,
+    token: String,
+    event: GitHubEvent,
+    data: Json<Value>,
+    idempotency: IdempotencyKey,
+) -> Result<EmptyResponse> {
+    let webhook = webhook_id.as_webhook(db).await?;
+    webhook.assert_token(&token)?;
+
+    let data = match github_to_message(&event.0, &data) {
+        Some(data) => data,
+        None => return Ok(EmptyResponse),
+    };
+
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    webhook.throw_if_cannot_send(&data)?;
+
+    let channel = db.fetch_channel(&webhook.channel_id).await?;
+
+    Message::create_from_api(
+        db,
+        channel,
+        data,
+        MessageAuthor::Webhook(&webhook),
+        idempotency,
+        true,
+    )
+    .await
+    .map(|_| EmptyResponse)
+}
@@ -0,0 +1,52 @@
+use chat_core::{
+    models::{message::MessageAuthor, Message},
+    permissions::{defn::ChannelPermission, r#impl::PermissionValue},
+    util::{idempotency::IdempotencyKey, reference::Reference},
+    Database, EmptyResponse, Error, Result,
+};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+use crate::util::webhook_formats::SlackPayload;
+
+/// # Executes a webhook with a Slack payload
+///
+/// Accepts Slack's incoming webhook format, attachments are sent as embeds.
+#[openapi(tag = "Webhooks")]
+#[post("/<webhook_id>/<token>/slack", data = "<data>")]
+pub async fn webhook_execute_slack(
+    db: &State<Database>,
+    webhook_id: Reference,
+    token: String,
+    data: Json<SlackPayload>,
+    idempotency: IdempotencyKey,
+) -> Result<EmptyResponse> {
+    let webhook = webhook_id.as_webhook(db).await?;
+    webhook.assert_token(&token)?;
+
+    let mut data = data.into_inner().into_message();
+
+    // Slack integrations set a username by default, only honour it if allowed
+    let permissions: PermissionValue = webhook.permissions.into();
+    if !permissions.has_channel_permission(ChannelPermission::Masquerade) {
+        data.masquerade = None;
+    }
+
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    webhook.throw_if_cannot_send(&data)?;
+
+    let channel = db.fetch_channel(&webhook.channel_id).await?;
+
+    Message::create_from_api(
+        db,
+        channel,
+        data,
+        MessageAuthor::Webhook(&webhook),
+        idempotency,
+        true,
+    )
+    .await
+    .map(|_| EmptyResponse)
+}
@@ -1 +1,2 @@
 pub mod ratelimiter;
+pub mod webhook_formats;
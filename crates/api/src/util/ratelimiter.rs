@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     ops::Add,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
@@ -7,8 +8,18 @@ use std::collections::hash_map::DefaultHasher;
 use std::hash::Hasher;
 
 use authifier::models::Session;
+use chat_core::{
+    models::User,
+    redis_kiss::get_connection,
+    variables::delta::{
+        MONO_CHAT_BOT_RATELIMITS, MONO_CHAT_RATELIMITS, MONO_CHAT_RATELIMIT_BACKEND,
+    },
+    Database,
+};
 use dashmap::DashMap;
+use moka::sync::Cache;
 use once_cell::sync::Lazy;
+use redis::{AsyncCommands, Script};
 use revolt_rocket_okapi::{
     gen::OpenApiGenerator,
     request::{OpenApiFromRequest, RequestHeaderInput},
@@ -20,27 +31,150 @@ use rocket::{
     serde::json::Json,
     Data, Request, Response,
 };
+use schemars::JsonSchema;
 use serde::Serialize;
 
 #[derive(Clone, Copy, Debug)]
 struct Entry {
-    used: u8,
+    used: u32,
     reset: u128,
 }
 
+/// Length of each ratelimit window
+const WINDOW: Duration = Duration::from_secs(10);
+
+/// How long ratelimit overrides are cached for before being fetched again
+const OVERRIDE_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Requests allowed per window in each bucket unless configured otherwise
+const DEFAULT_LIMITS: &[(&str, u32)] = &[
+    ("user_edit", 2),
+    ("users", 20),
+    ("bots", 10),
+    ("messaging", 10),
+    ("channels", 15),
+    ("servers", 5),
+    ("auth", 15),
+    ("auth_delete", 255),
+    ("default_avatar", 255),
+    ("swagger", 100),
+    ("safety", 15),
+    ("safety_report", 3),
+    ("any", 20),
+];
+
+/// Buckets which are tracked separately for each resource they apply to
+const SCOPED_BUCKETS: &[&str] = &["user_edit", "messaging", "channels", "servers"];
+
+static USER_LIMITS: Lazy<HashMap<String, u32>> = Lazy::new(|| load_limits(&MONO_CHAT_RATELIMITS));
+static BOT_LIMITS: Lazy<HashMap<String, u32>> =
+    Lazy::new(|| load_limits(&MONO_CHAT_BOT_RATELIMITS));
+
+/// Users whose overrides are kept cached at once
+const OVERRIDE_CACHE_CAPACITY: u64 = 10_000;
+
+static OVERRIDES: Lazy<Cache<String, Option<HashMap<String, u32>>>> = Lazy::new(|| {
+    Cache::builder()
+        .max_capacity(OVERRIDE_CACHE_CAPACITY)
+        .time_to_live(OVERRIDE_CACHE_TTL)
+        .build()
+});
+
 static MAP: Lazy<DashMap<u64, Entry>> = Lazy::new(DashMap::new);
 
+/// Increment a bucket and start its window if this is the first request,
+/// returning the amount used and milliseconds left in the window
+static SCRIPT: Lazy<Script> = Lazy::new(|| {
+    Script::new(
+        r"
+        local used = redis.call('INCR', KEYS[1])
+        local ttl = redis.call('PTTL', KEYS[1])
+
+        if ttl < 0 then
+            redis.call('PEXPIRE', KEYS[1], ARGV[1])
+            ttl = tonumber(ARGV[1])
+        end
+
+        return { used, ttl }
+        ",
+    )
+});
+
+/// Where bucket state is kept
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Backend {
+    /// Shared between all nodes through Redis
+    Redis,
+
+    /// Local to this process
+    Memory,
+}
+
+static BACKEND: Lazy<Backend> = Lazy::new(|| match MONO_CHAT_RATELIMIT_BACKEND.as_str() {
+    "memory" => Backend::Memory,
+    _ => Backend::Redis,
+});
+
 fn now() -> Duration {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .expect("Time went backwards..")
 }
 
+/// Build bucket limits from the defaults and a list of `bucket=limit` pairs
+fn load_limits(config: &str) -> HashMap<String, u32> {
+    let mut limits: HashMap<String, u32> = DEFAULT_LIMITS
+        .iter()
+        .map(|(bucket, limit)| (bucket.to_string(), *limit))
+        .collect();
+
+    for pair in config.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+        match pair
+            .split_once('=')
+            .and_then(|(bucket, limit)| Some((bucket.trim(), limit.trim().parse().ok()?)))
+        {
+            Some((bucket, limit)) if is_bucket(bucket) => {
+                limits.insert(bucket.to_string(), limit);
+            }
+            _ => warn!("Ignoring invalid ratelimit configuration `{pair}`."),
+        }
+    }
+
+    limits
+}
+
+/// Whether the given name refers to a ratelimit bucket
+pub fn is_bucket(bucket: &str) -> bool {
+    DEFAULT_LIMITS.iter().any(|(name, _)| *name == bucket)
+}
+
+/// Forget any cached override for a user so changes apply immediately on this node
+pub fn invalidate_override(user_id: &str) {
+    OVERRIDES.invalidate(user_id);
+}
+
+/// Fetch the bucket overrides for a user, caching the result
+async fn fetch_overrides(db: &Database, user_id: &str) -> Option<HashMap<String, u32>> {
+    if let Some(buckets) = OVERRIDES.get(user_id) {
+        return buckets;
+    }
+
+    let buckets = db
+        .fetch_ratelimit_override(user_id)
+        .await
+        .ok()
+        .map(|ratelimit_override| ratelimit_override.buckets);
+
+    OVERRIDES.insert(user_id.to_string(), buckets.clone());
+
+    buckets
+}
+
 impl Entry {
     pub fn from(key: u64) -> Entry {
         MAP.get(&key).map(|x| *x).unwrap_or_else(|| Entry {
             used: 0,
-            reset: now().add(Duration::from_secs(10)).as_millis(),
+            reset: now().add(WINDOW).as_millis(),
         })
     }
 
@@ -49,7 +183,7 @@ impl Entry {
 
         if current_time > self.reset {
             self.used = 1;
-            self.reset = now().add(Duration::from_secs(10)).as_millis();
+            self.reset = now().add(WINDOW).as_millis();
         } else {
             self.used += 1;
         }
@@ -59,11 +193,11 @@ impl Entry {
         MAP.insert(key, self);
     }
 
-    pub fn get_remaining(&self, limit: u8) -> u8 {
+    pub fn get_remaining(&self, limit: u32) -> u32 {
         if now().as_millis() > self.reset {
             limit
         } else {
-            limit - self.used
+            limit.saturating_sub(self.used)
         }
     }
 
@@ -77,12 +211,171 @@ impl Entry {
     }
 }
 
+/// Who a request is ratelimited as
+#[derive(Clone, Debug)]
+pub struct RatelimitIdentity {
+    /// Session, bot or IP address which owns the buckets
+    identifier: String,
+
+    /// Authenticated user or bot
+    user_id: Option<String>,
+    bot: bool,
+}
+
+impl RatelimitIdentity {
+    /// Find the number of requests this caller may make per window in a bucket
+    pub async fn limit(&self, db: &Database, bucket: &str) -> u32 {
+        if let Some(user_id) = &self.user_id {
+            if let Some(limit) = fetch_overrides(db, user_id)
+                .await
+                .and_then(|buckets| buckets.get(bucket).copied())
+            {
+                return limit;
+            }
+        }
+
+        let limits = if self.bot {
+            &*BOT_LIMITS
+        } else {
+            &*USER_LIMITS
+        };
+        limits
+            .get(bucket)
+            .or_else(|| limits.get("any"))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Compute the key a bucket is stored under
+    fn key(&self, bucket: &str, resource: Option<&str>) -> u64 {
+        let mut key = DefaultHasher::new();
+        key.write(self.identifier.as_bytes());
+        key.write(bucket.as_bytes());
+
+        if let Some(id) = resource {
+            key.write(id.as_bytes());
+        }
+
+        key.finish()
+    }
+
+    /// Compute the key a bucket is shared under in Redis
+    ///
+    /// Unlike [`RatelimitIdentity::key`], this stays the same across builds so
+    /// every replica agrees on it.
+    fn shared_key(&self, bucket: &str, resource: Option<&str>) -> String {
+        format!(
+            "ratelimit:{}:{bucket}:{}",
+            self.identifier,
+            resource.unwrap_or_default()
+        )
+    }
+
+    /// Report the state of every bucket for this caller
+    ///
+    /// Buckets which are tracked per resource are only included if a resource is given.
+    pub async fn bucket_states(&self, db: &Database, resource: Option<&str>) -> Vec<BucketState> {
+        let mut states = vec![];
+
+        for (bucket, _) in DEFAULT_LIMITS {
+            let resource = if SCOPED_BUCKETS.contains(bucket) {
+                match resource {
+                    Some(resource) => Some(resource),
+                    None => continue,
+                }
+            } else {
+                None
+            };
+
+            let key = self.key(bucket, resource);
+            let limit = self.limit(db, bucket).await;
+
+            let (used, reset_after) = match *BACKEND {
+                Backend::Redis => match peek_redis(&self.shared_key(bucket, resource)).await {
+                    Some(state) => state,
+                    None => peek_memory(key),
+                },
+                Backend::Memory => peek_memory(key),
+            };
+
+            states.push(BucketState {
+                bucket: bucket.to_string(),
+                limit,
+                remaining: limit.saturating_sub(used),
+                reset_after,
+            });
+        }
+
+        states
+    }
+}
+
+#[async_trait]
+impl<'r> FromRequest<'r> for RatelimitIdentity {
+    type Error = ();
+
+    async fn from_request(request: &'r rocket::Request<'_>) -> Outcome<Self, Self::Error> {
+        let identity = request
+            .local_cache_async(async {
+                use rocket::outcome::Outcome;
+                if let Outcome::Success(session) = request.guard::<Session>().await {
+                    RatelimitIdentity {
+                        identifier: session.id,
+                        user_id: Some(session.user_id),
+                        bot: false,
+                    }
+                } else if let Outcome::Success(user) = request.guard::<User>().await {
+                    RatelimitIdentity {
+                        identifier: user.id.to_string(),
+                        bot: user.bot.is_some(),
+                        user_id: Some(user.id),
+                    }
+                } else {
+                    RatelimitIdentity {
+                        identifier: to_real_ip(request),
+                        user_id: None,
+                        bot: false,
+                    }
+                }
+            })
+            .await;
+
+        Outcome::Success(identity.clone())
+    }
+}
+
+impl<'r> OpenApiFromRequest<'r> for RatelimitIdentity {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> revolt_rocket_okapi::Result<RequestHeaderInput> {
+        Ok(RequestHeaderInput::None)
+    }
+}
+
+/// # Bucket State
+#[derive(Serialize, JsonSchema, Debug)]
+pub struct BucketState {
+    /// Bucket name
+    bucket: String,
+
+    /// Requests allowed per window
+    limit: u32,
+
+    /// Requests left in the current window
+    remaining: u32,
+
+    /// Milliseconds until the current window ends
+    reset_after: u128,
+}
+
 #[derive(Serialize, Clone, Copy, Debug)]
 #[allow(dead_code)]
 pub struct Ratelimiter {
     key: u64,
-    limit: u8,
-    remaining: u8,
+    limit: u32,
+    remaining: u32,
     reset: u128,
 }
 
@@ -119,7 +412,7 @@ fn resolve_bucket<'r>(request: &'r rocket::Request<'_>) -> (&'r str, Option<&'r
                 }
             }
             ("swagger", _, _) => ("swagger", None),
-            ("safety", Some("report"), _) => ("safety_report", Some("report")),
+            ("safety", Some("report"), _) => ("safety_report", None),
             ("safety", _, _) => ("safety", None),
             _ => ("any", None),
         }
@@ -128,24 +421,6 @@ fn resolve_bucket<'r>(request: &'r rocket::Request<'_>) -> (&'r str, Option<&'r
     }
 }
 
-fn resolve_bucket_limit(bucket: &str) -> u8 {
-    match bucket {
-        "user_edit" => 2,
-        "users" => 20,
-        "bots" => 10,
-        "messaging" => 10,
-        "channels" => 15,
-        "servers" => 5,
-        "auth" => 15,
-        "auth_delete" => 255,
-        "default_avatar" => 255,
-        "swagger" => 100,
-        "safety" => 15,
-        "safety_report" => 3,
-        _ => 20,
-    }
-}
-
 fn to_ip(request: &'_ rocket::Request<'_>) -> String {
     request
         .remote()
@@ -165,40 +440,92 @@ fn to_real_ip(request: &'_ rocket::Request<'_>) -> String {
     }
 }
 
-impl Ratelimiter {
-    /// Generate guard from identifier and target bucket
-    pub fn from(
-        identifier: &str,
-        (bucket, resource): (&str, Option<&str>),
-    ) -> Result<Ratelimiter, u128> {
-        let mut key = DefaultHasher::new();
-        key.write(identifier.as_bytes());
-        key.write(bucket.as_bytes());
+/// Consume one request from a bucket held in process memory
+///
+/// Returns the remaining requests and time until reset,
+/// or the time until reset if the bucket is exhausted.
+fn consume_memory(key: u64, limit: u32) -> Result<(u32, u128), u128> {
+    let mut entry = Entry::from(key);
 
-        if let Some(id) = resource {
-            key.write(id.as_bytes());
-        }
+    let remaining = entry.get_remaining(limit);
+    if remaining > 0 {
+        entry.deduct();
 
-        let key = key.finish();
-        let limit = resolve_bucket_limit(bucket);
-        let mut entry = Entry::from(key);
+        let reset = entry.left_until_reset();
+        entry.save(key);
 
-        let remaining = entry.get_remaining(limit);
-        if remaining > 0 {
-            entry.deduct();
+        Ok((remaining - 1, reset))
+    } else {
+        Err(entry.left_until_reset())
+    }
+}
 
-            let reset = entry.left_until_reset();
-            entry.save(key);
+/// Consume one request from a bucket held in Redis
+///
+/// Returns `None` if Redis could not be reached.
+async fn consume_redis(key: &str, limit: u32) -> Option<Result<(u32, u128), u128>> {
+    let mut conn = get_connection().await.ok()?;
+    let (used, reset): (i64, i64) = SCRIPT
+        .key(key)
+        .arg(WINDOW.as_millis() as u64)
+        .invoke_async(&mut *conn)
+        .await
+        .ok()?;
+
+    let reset = reset.max(0) as u128;
+    if used <= limit as i64 {
+        Some(Ok((limit - used as u32, reset)))
+    } else {
+        Some(Err(reset))
+    }
+}
 
-            Ok(Ratelimiter {
-                key,
-                limit,
-                remaining: remaining - 1,
-                reset,
-            })
-        } else {
-            Err(entry.left_until_reset())
-        }
+/// Read a bucket held in process memory without consuming from it
+fn peek_memory(key: u64) -> (u32, u128) {
+    match MAP.get(&key) {
+        Some(entry) if entry.left_until_reset() > 0 => (entry.used, entry.left_until_reset()),
+        _ => (0, 0),
+    }
+}
+
+/// Read a bucket held in Redis without consuming from it
+async fn peek_redis(key: &str) -> Option<(u32, u128)> {
+    let mut conn = get_connection().await.ok()?;
+
+    let used: Option<u32> = conn.get(key).await.ok()?;
+    let reset: i64 = conn.pttl(key).await.ok()?;
+
+    Some((used.unwrap_or_default(), reset.max(0) as u128))
+}
+
+impl Ratelimiter {
+    /// Generate guard from caller and target bucket
+    pub async fn from(
+        db: &Database,
+        identity: &RatelimitIdentity,
+        (bucket, resource): (&str, Option<&str>),
+    ) -> Result<Ratelimiter, u128> {
+        let key = identity.key(bucket, resource);
+        let shared_key = identity.shared_key(bucket, resource);
+        let limit = identity.limit(db, bucket).await;
+
+        let result = match *BACKEND {
+            Backend::Redis => match consume_redis(&shared_key, limit).await {
+                Some(result) => result,
+                None => {
+                    warn!("Failed to reach Redis, falling back to local ratelimits.");
+                    consume_memory(key, limit)
+                }
+            },
+            Backend::Memory => consume_memory(key, limit),
+        };
+
+        result.map(|(remaining, reset)| Ratelimiter {
+            key,
+            limit,
+            remaining,
+            reset,
+        })
     }
 }
 
@@ -209,15 +536,14 @@ impl<'r> FromRequest<'r> for Ratelimiter {
     async fn from_request(request: &'r rocket::Request<'_>) -> Outcome<Self, Self::Error> {
         let ratelimiter = request
             .local_cache_async(async {
-                use rocket::outcome::Outcome;
-                let identifier = if let Outcome::Success(session) = request.guard::<Session>().await
-                {
-                    session.id
-                } else {
-                    to_real_ip(request)
-                };
-
-                Ratelimiter::from(&identifier, resolve_bucket(request))
+                let db = request.rocket().state::<Database>().expect("`Database`");
+                let identity = request
+                    .guard::<RatelimitIdentity>()
+                    .await
+                    .succeeded()
+                    .expect("identity is always resolved");
+
+                Ratelimiter::from(db, &identity, resolve_bucket(request)).await
             })
             .await;
 
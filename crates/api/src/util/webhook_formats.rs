@@ -0,0 +1,505 @@
+use chat_core::{
+    models::message::{DataMessageSend, Masquerade, SendableEmbed},
+    Error,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use revolt_rocket_okapi::{
+    gen::OpenApiGenerator,
+    request::{OpenApiFromRequest, RequestHeaderInput},
+    revolt_okapi::openapi3::{Parameter, ParameterValue},
+};
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+};
+use schemars::schema::{InstanceType, SchemaObject, SingleOrVec};
+use serde::Deserialize;
+use serde_json::Value;
+
+static RE_SLACK_LINK: Lazy<Regex> = Lazy::new(|| Regex::new(r"<([^<>|]+)\|([^<>]+)>").unwrap());
+static RE_SLACK_ANGLED: Lazy<Regex> = Lazy::new(|| Regex::new(r"<([^<>|]+)>").unwrap());
+
+/// Cut a string down to at most `max` characters
+fn truncate(value: &str, max: usize) -> String {
+    if value.chars().count() > max {
+        let mut value: String = value.chars().take(max - 1).collect();
+        value.push('…');
+        value
+    } else {
+        value.to_string()
+    }
+}
+
+/// Combined size of content and embed descriptions allowed by `Message::validate_sum`
+const MESSAGE_BUDGET: usize = 2000;
+
+/// Cut text down to fit the remaining message budget, counted in bytes
+///
+/// Returns `None` once the budget is used up.
+fn fit_budget(value: Option<String>, budget: &mut usize) -> Option<String> {
+    let value = value?;
+    if value.len() <= *budget {
+        *budget -= value.len();
+        return Some(value);
+    }
+
+    let ellipsis = '…'.len_utf8();
+    if *budget <= ellipsis {
+        return None;
+    }
+
+    let mut end = *budget - ellipsis;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    *budget = 0;
+    Some(format!("{}…", &value[..end]))
+}
+
+/// Turn free text into an optional embed field respecting its length limits
+fn field(value: Option<String>, max: usize) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .map(|v| truncate(&v, max))
+}
+
+/// Only keep URLs which fit within embed icon limits
+fn icon(url: Option<String>) -> Option<String> {
+    url.filter(|v| !v.is_empty() && v.len() <= 128)
+}
+
+/// Only keep links of a reasonable length, dropping the rest
+fn link(url: Option<String>) -> Option<String> {
+    url.filter(|v| !v.is_empty() && v.len() <= 256)
+}
+
+/// Convert Slack `mrkdwn` into Markdown
+fn from_mrkdwn(text: &str) -> String {
+    let text = RE_SLACK_LINK.replace_all(text, "[$2]($1)");
+    RE_SLACK_ANGLED
+        .replace_all(&text, |captures: &regex::Captures| {
+            let inner = &captures[1];
+            match inner {
+                "!here" | "!channel" | "!everyone" => format!("@{}", &inner[1..]),
+                _ => inner.to_string(),
+            }
+        })
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Slack attachment colours may be keywords or hex codes
+fn slack_colour(colour: Option<String>) -> Option<String> {
+    colour.and_then(|colour| match colour.as_str() {
+        "good" => Some("#2eb886".to_string()),
+        "warning" => Some("#daa038".to_string()),
+        "danger" => Some("#a30200".to_string()),
+        _ => {
+            let hex = colour.trim_start_matches('#');
+            if matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                Some(format!("#{hex}"))
+            } else {
+                None
+            }
+        }
+    })
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct SlackAttachmentField {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    value: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct SlackAttachment {
+    fallback: Option<String>,
+    color: Option<String>,
+    pretext: Option<String>,
+    author_name: Option<String>,
+    author_icon: Option<String>,
+    title: Option<String>,
+    title_link: Option<String>,
+    text: Option<String>,
+    #[serde(default)]
+    fields: Vec<SlackAttachmentField>,
+    footer: Option<String>,
+}
+
+/// # Slack Webhook Payload
+///
+/// Subset of Slack's incoming webhook format.
+#[derive(Deserialize, JsonSchema)]
+pub struct SlackPayload {
+    text: Option<String>,
+    username: Option<String>,
+    icon_url: Option<String>,
+    #[serde(default)]
+    attachments: Vec<SlackAttachment>,
+    /// Block Kit layout, only text from `header`, `section` and `context` blocks is used
+    #[serde(default)]
+    blocks: Vec<Value>,
+}
+
+impl SlackPayload {
+    pub fn into_message(self) -> DataMessageSend {
+        let mut content = self.text.as_deref().map(from_mrkdwn);
+
+        if content.is_none() {
+            let text = self
+                .blocks
+                .iter()
+                .filter_map(block_text)
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            if !text.is_empty() {
+                content = Some(text);
+            }
+        }
+
+        let mut embeds = self
+            .attachments
+            .into_iter()
+            .take(10)
+            .map(|attachment| {
+                let mut description = vec![];
+
+                if let Some(pretext) = attachment.pretext {
+                    description.push(from_mrkdwn(&pretext));
+                }
+
+                if let Some(text) = attachment.text.or(attachment.fallback) {
+                    description.push(from_mrkdwn(&text));
+                }
+
+                for entry in attachment.fields {
+                    description.push(format!(
+                        "**{}**\n{}",
+                        entry.title,
+                        from_mrkdwn(&entry.value)
+                    ));
+                }
+
+                if let Some(footer) = attachment.footer {
+                    description.push(format!("_{}_", from_mrkdwn(&footer)));
+                }
+
+                SendableEmbed {
+                    icon_url: icon(attachment.author_icon),
+                    url: link(attachment.title_link),
+                    title: field(attachment.title.or(attachment.author_name), 100),
+                    description: field(Some(description.join("\n\n")), 2000),
+                    media: None,
+                    colour: slack_colour(attachment.color),
+                }
+            })
+            .collect::<Vec<SendableEmbed>>();
+
+        let mut budget = MESSAGE_BUDGET;
+        let content = fit_budget(field(content, 2000), &mut budget);
+        for embed in &mut embeds {
+            embed.description = fit_budget(embed.description.take(), &mut budget);
+        }
+
+        let masquerade = if self.username.is_some() || self.icon_url.is_some() {
+            Some(Masquerade {
+                name: field(self.username, 32),
+                avatar: self.icon_url.filter(|v| !v.is_empty() && v.len() <= 256),
+                colour: None,
+            })
+        } else {
+            None
+        };
+
+        DataMessageSend {
+            nonce: None,
+            content,
+            attachments: None,
+            replies: None,
+            embeds: if embeds.is_empty() {
+                None
+            } else {
+                Some(embeds)
+            },
+            masquerade,
+            interactions: None,
+            components: None,
+        }
+    }
+}
+
+/// Pull readable text out of a Block Kit block
+fn block_text(block: &Value) -> Option<String> {
+    match block["type"].as_str()? {
+        "header" | "section" => block["text"]["text"].as_str().map(from_mrkdwn),
+        "context" => Some(
+            block["elements"]
+                .as_array()?
+                .iter()
+                .filter_map(|element| element["text"].as_str())
+                .map(from_mrkdwn)
+                .collect::<Vec<String>>()
+                .join(" "),
+        ),
+        _ => None,
+    }
+}
+
+/// Event name sent by GitHub in the `X-GitHub-Event` header
+pub struct GitHubEvent(pub String);
+
+#[async_trait]
+impl<'r> FromRequest<'r> for GitHubEvent {
+    type Error = Error;
+
+    async fn from_request(request: &'r rocket::Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.headers().get_one("X-GitHub-Event") {
+            Some(event) => Outcome::Success(GitHubEvent(event.to_string())),
+            None => Outcome::Failure((Status::BadRequest, Error::InvalidOperation)),
+        }
+    }
+}
+
+impl<'r> OpenApiFromRequest<'r> for GitHubEvent {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> revolt_rocket_okapi::Result<RequestHeaderInput> {
+        Ok(RequestHeaderInput::Parameter(Parameter {
+            name: "X-GitHub-Event".to_string(),
+            description: Some("Type of GitHub event being delivered".to_string()),
+            allow_empty_value: false,
+            required: true,
+            deprecated: false,
+            extensions: schemars::Map::new(),
+            location: "header".to_string(),
+            value: ParameterValue::Schema {
+                allow_reserved: false,
+                example: None,
+                examples: None,
+                explode: None,
+                style: None,
+                schema: SchemaObject {
+                    instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::String))),
+                    ..Default::default()
+                },
+            },
+        }))
+    }
+}
+
+const GITHUB_DEFAULT: &str = "#24292f";
+const GITHUB_OPENED: &str = "#1f883d";
+const GITHUB_CLOSED: &str = "#cf222e";
+const GITHUB_MERGED: &str = "#8250df";
+
+/// First line of a commit message or similar text
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or_default()
+}
+
+/// Translate a GitHub webhook delivery into a message
+///
+/// Returns `None` for events which should not produce a message, such as `ping`.
+pub fn github_to_message(event: &str, payload: &Value) -> Option<DataMessageSend> {
+    let repository = payload["repository"]["full_name"]
+        .as_str()
+        .unwrap_or("GitHub");
+    let action = payload["action"].as_str().unwrap_or_default();
+
+    let (title, url, description, colour) = match event {
+        "ping" => return None,
+        "push" => {
+            let branch = payload["ref"]
+                .as_str()
+                .unwrap_or_default()
+                .trim_start_matches("refs/heads/");
+            let commits = payload["commits"].as_array()?;
+
+            // Branch creation and deletion also arrive as pushes without commits
+            if commits.is_empty() {
+                return None;
+            }
+
+            let lines = commits
+                .iter()
+                .take(10)
+                .map(|commit| {
+                    let id = commit["id"].as_str().unwrap_or_default();
+                    format!(
+                        "[`{}`]({}) {} - {}",
+                        id.chars().take(7).collect::<String>(),
+                        commit["url"].as_str().unwrap_or_default(),
+                        first_line(commit["message"].as_str().unwrap_or_default()),
+                        commit["author"]["username"]
+                            .as_str()
+                            .or_else(|| commit["author"]["name"].as_str())
+                            .unwrap_or_default()
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            (
+                format!(
+                    "[{repository}:{branch}] {} new commit{}",
+                    commits.len(),
+                    if commits.len() == 1 { "" } else { "s" }
+                ),
+                payload["compare"].as_str(),
+                Some(lines),
+                GITHUB_DEFAULT,
+            )
+        }
+        "pull_request" => {
+            let pull_request = &payload["pull_request"];
+            let (action, colour) = match action {
+                "closed" if pull_request["merged"].as_bool() == Some(true) => {
+                    ("merged", GITHUB_MERGED)
+                }
+                "closed" => ("closed", GITHUB_CLOSED),
+                "opened" | "reopened" => (action, GITHUB_OPENED),
+                _ => (action, GITHUB_DEFAULT),
+            };
+
+            (
+                format!(
+                    "[{repository}] Pull request {action}: #{} {}",
+                    pull_request["number"],
+                    pull_request["title"].as_str().unwrap_or_default()
+                ),
+                pull_request["html_url"].as_str(),
+                if action == "opened" {
+                    pull_request["body"].as_str().map(str::to_string)
+                } else {
+                    None
+                },
+                colour,
+            )
+        }
+        "issues" => {
+            let issue = &payload["issue"];
+            let colour = match action {
+                "opened" | "reopened" => GITHUB_OPENED,
+                "closed" => GITHUB_CLOSED,
+                _ => GITHUB_DEFAULT,
+            };
+
+            (
+                format!(
+                    "[{repository}] Issue {action}: #{} {}",
+                    issue["number"],
+                    issue["title"].as_str().unwrap_or_default()
+                ),
+                issue["html_url"].as_str(),
+                if action == "opened" {
+                    issue["body"].as_str().map(str::to_string)
+                } else {
+                    None
+                },
+                colour,
+            )
+        }
+        "issue_comment" => {
+            // Edits and deletions of comments are noise in a channel
+            if action != "created" {
+                return None;
+            }
+
+            let issue = &payload["issue"];
+            (
+                format!(
+                    "[{repository}] New comment on #{} {}",
+                    issue["number"],
+                    issue["title"].as_str().unwrap_or_default()
+                ),
+                payload["comment"]["html_url"].as_str(),
+                payload["comment"]["body"].as_str().map(str::to_string),
+                GITHUB_DEFAULT,
+            )
+        }
+        "release" => {
+            if action != "published" {
+                return None;
+            }
+
+            let release = &payload["release"];
+            (
+                format!(
+                    "[{repository}] New release published: {}",
+                    release["name"]
+                        .as_str()
+                        .filter(|v| !v.is_empty())
+                        .or_else(|| release["tag_name"].as_str())
+                        .unwrap_or_default()
+                ),
+                release["html_url"].as_str(),
+                release["body"].as_str().map(str::to_string),
+                GITHUB_OPENED,
+            )
+        }
+        "star" => {
+            if action != "created" {
+                return None;
+            }
+
+            (
+                format!("[{repository}] New star added"),
+                payload["repository"]["html_url"].as_str(),
+                None,
+                GITHUB_DEFAULT,
+            )
+        }
+        _ => (
+            if action.is_empty() {
+                format!("[{repository}] {event}")
+            } else {
+                format!("[{repository}] {event} {action}")
+            },
+            payload["repository"]["html_url"].as_str(),
+            None,
+            GITHUB_DEFAULT,
+        ),
+    };
+
+    let sender = &payload["sender"];
+    let author = sender["login"].as_str().map(|login| format!("@{login}"));
+    let mut budget = MESSAGE_BUDGET;
+
+    Some(DataMessageSend {
+        nonce: None,
+        content: None,
+        attachments: None,
+        replies: None,
+        embeds: Some(vec![SendableEmbed {
+            icon_url: icon(sender["avatar_url"].as_str().map(str::to_string)),
+            url: link(url.map(str::to_string)),
+            title: field(Some(title), 100),
+            description: fit_budget(
+                field(
+                    match (description, author) {
+                        (Some(description), Some(author)) => {
+                            Some(format!("{description}\n\n{author}"))
+                        }
+                        (description, author) => description.or(author),
+                    },
+                    2000,
+                ),
+                &mut budget,
+            ),
+            media: None,
+            colour: Some(colour.to_string()),
+        }]),
+        masquerade: None,
+        interactions: None,
+        components: None,
+    })
+}
@@ -0,0 +1,107 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use futures::{channel::mpsc, StreamExt};
+
+use crate::{events::client::EventV1, Result};
+
+use super::{EventBus, EventSubscription};
+
+/// Subscribers to each topic, keyed by subscription ID
+type Topics = HashMap<String, HashMap<u64, mpsc::UnboundedSender<EventV1>>>;
+
+/// Event bus which only delivers events within this process
+///
+/// Suitable for deployments which run every service in a single process,
+/// which then do not need Redis for realtime events.
+#[derive(Default)]
+pub struct LocalEventBus {
+    topics: Arc<Mutex<Topics>>,
+    next_id: AtomicU64,
+}
+
+/// Subscription registered directly with the local event bus
+struct LocalSubscription {
+    id: u64,
+    topics: Arc<Mutex<Topics>>,
+    subscribed: HashSet<String>,
+    sender: mpsc::UnboundedSender<EventV1>,
+    events: mpsc::UnboundedReceiver<EventV1>,
+}
+
+#[async_trait]
+impl EventBus for LocalEventBus {
+    async fn publish(&self, topic: String, event: EventV1) {
+        let topics = self.topics.lock().expect("Event bus lock poisoned.");
+        if let Some(subscribers) = topics.get(&topic) {
+            for sender in subscribers.values() {
+                sender.unbounded_send(event.clone()).ok();
+            }
+        }
+    }
+
+    async fn open_subscription(&self) -> Result<Box<dyn EventSubscription>> {
+        let (sender, events) = mpsc::unbounded();
+
+        Ok(Box::new(LocalSubscription {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            topics: self.topics.clone(),
+            subscribed: HashSet::new(),
+            sender,
+            events,
+        }))
+    }
+}
+
+#[async_trait]
+impl EventSubscription for LocalSubscription {
+    async fn subscribe(&mut self, topic: String) {
+        self.topics
+            .lock()
+            .expect("Event bus lock poisoned.")
+            .entry(topic.clone())
+            .or_default()
+            .insert(self.id, self.sender.clone());
+
+        self.subscribed.insert(topic);
+    }
+
+    async fn unsubscribe(&mut self, topic: String) {
+        remove_subscriber(
+            &mut self.topics.lock().expect("Event bus lock poisoned."),
+            &topic,
+            self.id,
+        );
+        self.subscribed.remove(&topic);
+    }
+
+    async fn next(&mut self) -> Option<EventV1> {
+        self.events.next().await
+    }
+}
+
+impl Drop for LocalSubscription {
+    fn drop(&mut self) {
+        if let Ok(mut topics) = self.topics.lock() {
+            for topic in &self.subscribed {
+                remove_subscriber(&mut topics, topic, self.id);
+            }
+        }
+    }
+}
+
+/// Remove a subscriber from a topic, dropping the topic once nobody is left
+fn remove_subscriber(topics: &mut Topics, topic: &str, id: u64) {
+    if let Some(subscribers) = topics.get_mut(topic) {
+        subscribers.remove(&id);
+
+        if subscribers.is_empty() {
+            topics.remove(topic);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use once_cell::sync::Lazy;
+
+use crate::{variables::delta::MONO_CHAT_EVENT_BUS, Result};
+
+use super::client::EventV1;
+
+mod local;
+mod redis;
+
+pub use self::local::LocalEventBus;
+pub use self::redis::RedisEventBus;
+
+/// Publish and subscribe transport for events
+///
+/// Topics are plain strings: a user, channel or server ID, `{id}!` for
+/// a user's private topic, or `global`.
+#[async_trait]
+pub trait EventBus: Sync + Send {
+    /// Publish an event to every subscriber of a topic
+    async fn publish(&self, topic: String, event: EventV1);
+
+    /// Open a new subscription which is not subscribed to any topics yet
+    async fn open_subscription(&self) -> Result<Box<dyn EventSubscription>>;
+}
+
+/// Set of topics a single client is subscribed to
+#[async_trait]
+pub trait EventSubscription: Send {
+    /// Start receiving events published to a topic
+    async fn subscribe(&mut self, topic: String);
+
+    /// Stop receiving events published to a topic
+    async fn unsubscribe(&mut self, topic: String);
+
+    /// Wait for the next event on any subscribed topic
+    ///
+    /// Returns None once the subscription can no longer receive events.
+    async fn next(&mut self) -> Option<EventV1>;
+}
+
+static EVENT_BUS: Lazy<Box<dyn EventBus>> = Lazy::new(create_event_bus);
+
+/// Create the event bus selected by `MONO_CHAT_EVENT_BUS`
+fn create_event_bus() -> Box<dyn EventBus> {
+    match MONO_CHAT_EVENT_BUS.as_str() {
+        "redis" => Box::new(RedisEventBus),
+        "local" => Box::<LocalEventBus>::default(),
+        other => panic!("Unknown event bus `{other}`, expected `redis` or `local`."),
+    }
+}
+
+/// Event bus configured for this process
+pub fn event_bus() -> &'static dyn EventBus {
+    EVENT_BUS.as_ref()
+}
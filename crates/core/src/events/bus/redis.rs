@@ -0,0 +1,117 @@
+use async_std::task::spawn;
+use futures::{channel::mpsc, select, FutureExt, StreamExt};
+
+use crate::{events::client::EventV1, Error, Result};
+
+use super::{EventBus, EventSubscription};
+
+/// Event bus shared between every node through Redis pub/sub
+pub struct RedisEventBus;
+
+enum Command {
+    Subscribe(String),
+    Unsubscribe(String),
+}
+
+/// Subscription backed by its own Redis pub/sub connection
+///
+/// The connection is driven by a background task, which stops once
+/// this subscription is dropped or the connection fails.
+struct RedisSubscription {
+    commands: mpsc::UnboundedSender<Command>,
+    events: mpsc::UnboundedReceiver<EventV1>,
+}
+
+#[async_trait]
+impl EventBus for RedisEventBus {
+    async fn publish(&self, topic: String, event: EventV1) {
+        #[cfg(not(debug_assertions))]
+        redis_kiss::p(topic, event).await;
+
+        #[cfg(debug_assertions)]
+        redis_kiss::publish(topic, event).await.unwrap();
+    }
+
+    async fn open_subscription(&self) -> Result<Box<dyn EventSubscription>> {
+        let mut conn = redis_kiss::open_pubsub_connection()
+            .await
+            .map_err(|_| Error::InternalError)?;
+
+        let (commands, mut incoming_commands) = mpsc::unbounded();
+        let (outgoing_events, events) = mpsc::unbounded();
+
+        spawn(async move {
+            loop {
+                let message = select! {
+                    command = incoming_commands.next() => Err(command),
+                    message = conn.on_message().next().fuse() => Ok(message),
+                };
+
+                let message = match message {
+                    Ok(message) => message,
+                    Err(Some(Command::Subscribe(topic))) => {
+                        if let Err(e) = conn.subscribe(&topic).await {
+                            error!("Failed to subscribe to {topic}: {e:?}");
+                            break;
+                        }
+
+                        continue;
+                    }
+                    Err(Some(Command::Unsubscribe(topic))) => {
+                        if let Err(e) = conn.unsubscribe(&topic).await {
+                            error!("Failed to unsubscribe from {topic}: {e:?}");
+                            break;
+                        }
+
+                        continue;
+                    }
+                    Err(None) => break,
+                };
+
+                match message.map(|res| {
+                    res.map(|item| {
+                        (
+                            item.get_channel_name().to_string(),
+                            redis_kiss::decode_payload::<EventV1>(&item),
+                        )
+                    })
+                }) {
+                    Some(Ok((channel, item))) => {
+                        if let Ok(event) = item {
+                            if outgoing_events.unbounded_send(event).is_err() {
+                                break;
+                            }
+                        } else {
+                            warn!("Failed to deserialize an event for {channel}!");
+                        }
+                    }
+                    Some(Err(e)) => {
+                        info!("Error while consuming pub/sub messages: {e:?}");
+                        sentry::capture_error(&e);
+                        break;
+                    }
+                    None => break,
+                }
+            }
+        });
+
+        Ok(Box::new(RedisSubscription { commands, events }))
+    }
+}
+
+#[async_trait]
+impl EventSubscription for RedisSubscription {
+    async fn subscribe(&mut self, topic: String) {
+        self.commands.unbounded_send(Command::Subscribe(topic)).ok();
+    }
+
+    async fn unsubscribe(&mut self, topic: String) {
+        self.commands
+            .unbounded_send(Command::Unsubscribe(topic))
+            .ok();
+    }
+
+    async fn next(&mut self) -> Option<EventV1> {
+        self.events.next().await
+    }
+}
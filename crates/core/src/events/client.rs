@@ -1,14 +1,17 @@
 use authifier::AuthifierEvent;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     models::{
         channel::{FieldsChannel, FieldsWebhook, PartialChannel, PartialWebhook, Webhook},
-        message::{AppendMessage, PartialMessage},
+        message::{AppendMessage, MessageInteraction, PartialMessage},
+        report::ReportStatus,
         server::{FieldsRole, FieldsServer, PartialRole, PartialServer},
         server_member::{FieldsMember, MemberCompositeKey, PartialMember},
         user::{FieldsUser, PartialUser},
-        Channel, Emoji, Member, Message, Report, Server, User, UserSettings,
+        Channel, Emoji, Member, Message, Report, Server, ServerReport, User, UserSettings,
+        VoiceState,
     },
     Error,
 };
@@ -24,7 +27,7 @@ pub enum WebSocketError {
     MalformedData { msg: String },
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(untagged)]
 pub enum Ping {
     Binary(Vec<u8>),
@@ -45,7 +48,20 @@ pub enum EventV1 {
         v: Vec<EventV1>,
     },
 
-    Authenticated,
+    Authenticated {
+        /// Seconds between heartbeats the client is expected to send
+        heartbeat_interval: u64,
+    },
+
+    /// Resumable session assigned to this connection
+    Session {
+        id: String,
+    },
+
+    /// Missed events were replayed after resuming a session
+    Resumed {
+        replayed: usize,
+    },
 
     Ready {
         users: Vec<User>,
@@ -53,12 +69,33 @@ pub enum EventV1 {
         channels: Vec<Channel>,
         members: Vec<Member>,
         emojis: Option<Vec<Emoji>>,
+        voice_states: Vec<VoiceState>,
     },
 
     Pong {
         data: Ping,
     },
 
+    /// Member list subscription requested for an event stream, handled by the stream itself
+    StreamSubscribe {
+        id: String,
+    },
+
+    /// Member list subscription dropped for an event stream, handled by the stream itself
+    StreamUnsubscribe {
+        id: String,
+    },
+
+    /// Member list requested through a subscription
+    ///
+    /// Large servers are cut off at the member list limit, online members first.
+    MemberList {
+        id: String,
+        members: Vec<Member>,
+        users: Vec<User>,
+        truncated: bool,
+    },
+
     Message(Message),
 
     MessageUpdate {
@@ -103,6 +140,13 @@ pub enum EventV1 {
         ids: Vec<String>,
     },
 
+    InteractionCreate(MessageInteraction),
+
+    EphemeralMessage {
+        interaction_id: String,
+        message: Message,
+    },
+
     ChannelCreate(Channel),
 
     ChannelUpdate {
@@ -141,6 +185,15 @@ pub enum EventV1 {
         message_id: String,
     },
 
+    /// User joined a call or their voice state changed
+    VoiceStateUpdate(VoiceState),
+
+    /// User left a call
+    VoiceStateDelete {
+        id: String,
+        channel: String,
+    },
+
     ServerCreate {
         id: String,
         server: Server,
@@ -214,6 +267,14 @@ pub enum EventV1 {
 
     ReportCreate(Report),
 
+    /// Report filed with a server's moderators, only delivered to outbound webhooks
+    ServerReportCreate(ServerReport),
+
+    ReportUpdate {
+        id: String,
+        status: ReportStatus,
+    },
+
     WebhookCreate(Webhook),
 
     WebhookUpdate {
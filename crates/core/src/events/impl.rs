@@ -6,15 +6,18 @@ use crate::{
     database::Database,
     models::{
         server_member::FieldsMember,
-        user::{PartialUser, Presence, RelationshipStatus},
+        user::{PartialUser, Presence, RelationshipStatus, UserStatus},
         Channel, Member, User,
     },
     permissions::{defn::ChannelPermission, perms, r#impl::user::get_relationship},
-    presence::presence_filter_online,
-    Result,
+    presence::{presence_filter_effective, SessionPresence},
+    variables::delta::MONO_CHAT_MEMBER_LIST_LIMIT,
+    voice::voice_fetch_channel_states,
+    Error, Result,
 };
 
 use super::{
+    bus::event_bus,
     client::EventV1,
     state::{Cache, State},
 };
@@ -61,6 +64,24 @@ impl Cache {
         viewable_channels
     }
 
+    /// Whether a user is loaded up front in a lazy Ready payload
+    pub fn is_essential_user(&self, user_id: &str) -> bool {
+        if let Some(user) = self.users.get(&self.user_id) {
+            match get_relationship(user, user_id) {
+                RelationshipStatus::None => {
+                    let user_id = &user_id.to_string();
+                    self.channels.values().any(|channel| {
+                        matches!(channel, Channel::DirectMessage { recipients, .. } if recipients.contains(user_id))
+                    })
+                }
+                // Includes blocked users, so their profiles stay available.
+                _ => true,
+            }
+        } else {
+            false
+        }
+    }
+
     pub fn can_subscribe_to_user(&self, user_id: &str) -> bool {
         if let Some(user) = self.users.get(&self.user_id) {
             match get_relationship(user, user_id) {
@@ -91,6 +112,11 @@ impl Cache {
     }
 }
 
+/// Resolve the presence shown to other users from a user's stored status and effective session presence
+fn resolve_online_presence(status: &Option<UserStatus>, presence: SessionPresence) -> Presence {
+    presence.resolve(status.as_ref().and_then(|status| status.presence.as_ref()))
+}
+
 impl State {
     pub async fn generate_ready_payload(&mut self, db: &Database) -> Result<EventV1> {
         let mut user = self.clone_user();
@@ -119,14 +145,34 @@ impl State {
         let channels = self.cache.filter_accessible_channels(db, channels).await;
         for channel in &channels {
             match channel {
-                Channel::DirectMessage { recipients, .. } | Channel::Group { recipients, .. } => {
+                Channel::DirectMessage { recipients, .. } => {
+                    user_ids.append(&mut recipients.clone())
+                }
+                // Group members are loaded through member lists when lazy.
+                Channel::Group { recipients, .. } if !self.lazy => {
                     user_ids.append(&mut recipients.clone())
                 }
                 _ => {}
             }
         }
 
-        let online_ids = presence_filter_online(&user_ids).await;
+        let online_presence = presence_filter_effective(&user_ids).await;
+
+        let voice_states = voice_fetch_channel_states(
+            &channels
+                .iter()
+                .filter(|channel| {
+                    matches!(
+                        channel,
+                        Channel::VoiceChannel { .. }
+                            | Channel::Group { .. }
+                            | Channel::DirectMessage { .. }
+                    )
+                })
+                .map(|channel| channel.id().to_string())
+                .collect::<Vec<String>>(),
+        )
+        .await;
 
         user.online = true;
 
@@ -170,7 +216,13 @@ impl State {
         let mut users: Vec<User> = users
             .into_iter()
             .map(|mut x| {
-                x.online = online_ids.contains(&x.id.to_string());
+                if let Some(presence) = online_presence.get(&x.id) {
+                    x.online = true;
+                    x.online_presence = Some(resolve_online_presence(&x.status, *presence));
+                } else {
+                    x.online = false;
+                }
+
                 x.with_relationship(&user)
             })
             .collect();
@@ -198,6 +250,7 @@ impl State {
             channels,
             members,
             emojis,
+            voice_states,
         })
     }
 
@@ -273,8 +326,17 @@ impl State {
         }
     }
 
-    pub async fn broadcast_presence_change(&self, target: bool) {
-        if if let Some(status) = &self.cache.users.get(&self.cache.user_id).unwrap().status {
+    /// Broadcast a change to this user's online state or effective presence
+    ///
+    /// Session presence is never written to the database, it is only
+    /// resolved against the user's chosen presence and sent out.
+    pub async fn broadcast_presence_change(
+        &self,
+        target: bool,
+        session_presence: Option<SessionPresence>,
+    ) {
+        let status = &self.cache.users.get(&self.cache.user_id).unwrap().status;
+        if if let Some(status) = status {
             status.presence != Some(Presence::Invisible)
         } else {
             true
@@ -283,6 +345,8 @@ impl State {
                 id: self.cache.user_id.to_string(),
                 data: PartialUser {
                     online: Some(target),
+                    online_presence: session_presence
+                        .map(|presence| resolve_online_presence(status, presence)),
                     ..Default::default()
                 },
                 clear: vec![],
@@ -296,6 +360,118 @@ impl State {
         }
     }
 
+    /// Resolve the target of a member list subscription
+    ///
+    /// Server channels share the member list of their server.
+    fn member_list_target(&self, id: &str) -> Option<String> {
+        if self.cache.servers.contains_key(id) {
+            return Some(id.to_string());
+        }
+
+        match self.cache.channels.get(id) {
+            Some(Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. }) => {
+                Some(server.clone())
+            }
+            Some(Channel::DirectMessage { .. } | Channel::Group { .. }) => Some(id.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Load the member list of a server or channel and subscribe to those users
+    ///
+    /// Server member lists are limited to [`MONO_CHAT_MEMBER_LIST_LIMIT`] users,
+    /// filled with online members before offline ones.
+    pub async fn subscribe_member_list(&mut self, db: &Database, id: &str) -> Result<EventV1> {
+        let target = self.member_list_target(id).ok_or(Error::NotFound)?;
+
+        let (user_ids, server) = match self.cache.channels.get(&target) {
+            Some(Channel::DirectMessage { recipients, .. } | Channel::Group { recipients, .. }) => {
+                (recipients.clone(), false)
+            }
+            _ => (db.fetch_member_ids(&target).await?, true),
+        };
+
+        let user_ids: Vec<String> = user_ids
+            .into_iter()
+            .filter(|x| x != &self.cache.user_id)
+            .collect();
+
+        let online_presence = presence_filter_effective(&user_ids).await;
+
+        let limit = *MONO_CHAT_MEMBER_LIST_LIMIT;
+        let truncated = user_ids.len() > limit;
+        let user_ids: Vec<String> = if truncated {
+            let (online, offline): (Vec<String>, Vec<String>) = user_ids
+                .into_iter()
+                .partition(|x| online_presence.contains_key(x));
+
+            online.into_iter().chain(offline).take(limit).collect()
+        } else {
+            user_ids
+        };
+
+        let members = if server {
+            db.fetch_members(&target, &user_ids).await?
+        } else {
+            vec![]
+        };
+
+        let perspective = self.clone_user();
+        let users: Vec<User> = db
+            .fetch_users(&user_ids)
+            .await?
+            .into_iter()
+            .map(|mut x| {
+                if let Some(presence) = online_presence.get(&x.id) {
+                    x.online = true;
+                    x.online_presence = Some(resolve_online_presence(&x.status, *presence));
+                } else {
+                    x.online = false;
+                }
+
+                x.with_relationship(&perspective)
+            })
+            .collect();
+
+        for user in &users {
+            self.cache.users.insert(user.id.clone(), user.clone());
+            self.insert_subscription(user.id.clone());
+        }
+
+        self.member_lists
+            .insert(target.clone(), user_ids.into_iter().collect());
+
+        Ok(EventV1::MemberList {
+            id: target,
+            members,
+            users,
+            truncated,
+        })
+    }
+
+    /// Stop receiving updates for users only loaded through a member list
+    pub fn unsubscribe_member_list(&mut self, id: &str) {
+        let target = match self.member_list_target(id) {
+            Some(target) => target,
+            None => return,
+        };
+
+        if let Some(user_ids) = self.member_lists.remove(&target) {
+            for user_id in user_ids {
+                let retained = if self.lazy {
+                    self.cache.is_essential_user(&user_id)
+                } else {
+                    self.cache.can_subscribe_to_user(&user_id)
+                };
+
+                if !retained && !self.member_lists.values().any(|x| x.contains(&user_id)) {
+                    self.remove_subscription(&user_id);
+                    self.cache.users.remove(&user_id);
+                }
+            }
+        }
+    }
+
     pub async fn handle_incoming_event_v1(&mut self, db: &Database, event: &mut EventV1) -> bool {
         let mut queue_server = None;
 
@@ -417,6 +593,16 @@ impl State {
             }
 
             EventV1::ServerMemberUpdate { id, data, clear } => {
+                if self.lazy
+                    && id.user != self.cache.user_id
+                    && !self
+                        .member_lists
+                        .get(&id.server)
+                        .map_or(false, |x| x.contains(&id.user))
+                {
+                    return false;
+                }
+
                 if id.user == self.cache.user_id {
                     if let Some(member) = self.cache.members.get_mut(&id.server) {
                         for field in &clear.clone() {
@@ -460,6 +646,14 @@ impl State {
                 }
             }
 
+            // Server topics carry updates for every member, only pass on
+            // those for users the client has actually loaded.
+            EventV1::UserUpdate { id, .. } if self.lazy => {
+                if !self.cache.users.contains_key(id) {
+                    return false;
+                }
+            }
+
             EventV1::UserRelationship { id, user, .. } => {
                 self.cache.users.insert(id.clone(), user.clone());
 
@@ -491,14 +685,10 @@ impl State {
 
 impl EventV1 {
     pub async fn p(self, channel: String) {
-        #[cfg(not(debug_assertions))]
-        redis_kiss::p(channel, self).await;
-
         #[cfg(debug_assertions)]
         info!("Publishing event to {channel}: {self:?}");
 
-        #[cfg(debug_assertions)]
-        redis_kiss::publish(channel, self).await.unwrap();
+        event_bus().publish(channel, self).await;
     }
 
     pub async fn p_user(self, id: String, db: &Database) {
@@ -1,3 +1,4 @@
+pub mod bus;
 pub mod client;
 pub mod r#impl;
 pub mod server;
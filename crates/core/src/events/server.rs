@@ -1,12 +1,40 @@
 use serde::Deserialize;
 
+use crate::{models::user::UserActivity, presence::SessionPresence};
+
 use super::client::Ping;
 
 #[derive(Deserialize, Debug)]
 #[serde(tag = "type")]
 pub enum ClientMessage {
-    Authenticate { token: String },
-    BeginTyping { channel: String },
-    EndTyping { channel: String },
-    Ping { data: Ping, responded: Option<()> },
+    Authenticate {
+        token: String,
+    },
+    Resume {
+        token: String,
+        session_id: String,
+        seq: u64,
+    },
+    BeginTyping {
+        channel: String,
+    },
+    EndTyping {
+        channel: String,
+    },
+    Ping {
+        data: Ping,
+        responded: Option<()>,
+    },
+    SetActivity {
+        activity: Option<UserActivity>,
+    },
+    SetPresence {
+        presence: SessionPresence,
+    },
+    Subscribe {
+        id: String,
+    },
+    Unsubscribe {
+        id: String,
+    },
 }
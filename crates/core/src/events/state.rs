@@ -29,6 +29,11 @@ pub struct State {
     pub cache: Cache,
 
     pub private_topic: String,
+    /// Only load essential users up front, other users are loaded
+    /// through member list subscriptions (protocol version 2)
+    pub lazy: bool,
+    /// Users loaded through each member list subscription
+    pub(crate) member_lists: HashMap<String, HashSet<String>>,
     subscribed: HashSet<String>,
     state: SubscriptionStateChange,
 }
@@ -52,6 +57,8 @@ impl State {
         State {
             cache,
             private_topic,
+            lazy: false,
+            member_lists: HashMap::new(),
             subscribed,
             state: SubscriptionStateChange::Reset,
         }
@@ -83,6 +90,7 @@ impl State {
     pub fn reset_state(&mut self) {
         self.state = SubscriptionStateChange::Reset;
         self.subscribed.clear();
+        self.member_lists.clear();
     }
 
     pub fn insert_subscription(&mut self, subscription: String) {
@@ -121,7 +129,8 @@ impl State {
             SubscriptionStateChange::Change { remove, .. } => {
                 remove.push(subscription.to_string());
             }
-            SubscriptionStateChange::Reset => panic!("Should not remove during a reset!"),
+            // Only what remains in the set is subscribed to on reset.
+            SubscriptionStateChange::Reset => {}
         }
 
         self.subscribed.remove(subscription);
@@ -0,0 +1,26 @@
+use ulid::Ulid;
+
+use crate::{
+    models::admin_action::{AdminAction, AdminActionType},
+    Database, Result,
+};
+
+impl AdminAction {
+    /// Record an action taken by an administrator
+    pub async fn create(
+        db: &Database,
+        admin_id: &str,
+        target_id: Option<&str>,
+        action: AdminActionType,
+        reason: Option<String>,
+    ) -> Result<()> {
+        db.insert_admin_action(&AdminAction {
+            id: Ulid::new().to_string(),
+            admin_id: admin_id.to_string(),
+            target_id: target_id.map(|id| id.to_string()),
+            action,
+            reason,
+        })
+        .await
+    }
+}
@@ -15,6 +15,7 @@ use crate::{
     permissions::defn::OverrideField,
     tasks::ack::AckEvent,
     variables::delta::{MAX_GROUP_SIZE, MAX_SERVER_COUNT},
+    voice::{backend::voice_backend, voice_disconnect, voice_fetch_channel_states},
     Error, Result,
 };
 
@@ -191,6 +192,15 @@ impl Channel {
     /// Delete a channel
     pub async fn delete(self, db: &Database) -> Result<()> {
         let id = self.id().to_string();
+
+        for state in voice_fetch_channel_states(&[id.clone()]).await {
+            voice_backend()
+                .remove_participant(&id, &state.id)
+                .await
+                .ok();
+            voice_disconnect(&state.id).await;
+        }
+
         EventV1::ChannelDelete { id: id.clone() }.p(id).await;
         db.delete_channel(&self).await
     }
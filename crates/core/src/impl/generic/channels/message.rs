@@ -1,13 +1,16 @@
 use std::collections::HashSet;
 
+use redis_kiss::{get_connection, AsyncCommands};
 use ulid::Ulid;
+use validator::Validate;
 
 use crate::{
     database::Database,
     events::client::EventV1,
     models::{
         message::{
-            AppendMessage, BulkMessageResponse, DataMessageSend, Interactions, MessageAuthor,
+            ActionRow, AppendMessage, BulkMessageResponse, ButtonStyle, DataMessageSend,
+            InteractionResponse, Interactions, MessageAuthor, MessageComponent, MessageInteraction,
             PartialMessage, Reply, SendableEmbed, SystemMessage, RE_MENTION,
         },
         Channel, Emoji, File, Message, User,
@@ -21,9 +24,12 @@ use crate::{
     },
     util::idempotency::IdempotencyKey,
     variables::delta::MONO_CHAT_MAX_MESSAGE_REACTS,
-    Error, Result,
+    Error, Result, Timestamp,
 };
 
+/// How long a bot has to respond to an interaction (in seconds)
+static INTERACTION_TTL: usize = 15 * 60;
+
 impl Message {
     pub async fn create_no_web_push(
         &mut self,
@@ -35,6 +41,15 @@ impl Message {
 
         EventV1::Message(self.clone()).p(channel.to_string()).await;
 
+        if !is_direct_dm {
+            crate::tasks::outbound_webhooks::queue(
+                None,
+                Some(channel.to_string()),
+                EventV1::Message(self.clone()),
+            )
+            .await;
+        }
+
         crate::tasks::last_message_id::queue(
             channel.to_string(),
             self.id.to_string(),
@@ -116,13 +131,15 @@ impl Message {
     pub async fn update(&mut self, db: &Database, partial: PartialMessage) -> Result<()> {
         self.apply_options(partial.clone());
         let _ = db.update_message(&self.id, &partial).await;
-        EventV1::MessageUpdate {
+
+        let event = EventV1::MessageUpdate {
             id: self.id.clone(),
             channel: self.channel.clone(),
             data: partial,
-        }
-        .p(self.channel.clone())
-        .await;
+        };
+
+        event.clone().p(self.channel.clone()).await;
+        tasks::outbound_webhooks::queue(None, Some(self.channel.clone()), event).await;
         Ok(())
     }
 
@@ -138,12 +155,13 @@ impl Message {
 
         let _ = db.delete_message(&self.id).await;
 
-        EventV1::MessageDelete {
+        let event = EventV1::MessageDelete {
             id: self.id,
             channel: self.channel.clone(),
-        }
-        .p(self.channel)
-        .await;
+        };
+
+        event.clone().p(self.channel.clone()).await;
+        tasks::outbound_webhooks::queue(None, Some(self.channel), event).await;
         Ok(())
     }
 
@@ -253,6 +271,15 @@ impl Message {
             .p(self.channel.to_string())
             .await;
 
+        if !is_dm {
+            tasks::outbound_webhooks::queue(
+                None,
+                Some(self.channel.to_string()),
+                EventV1::Message(self.clone()),
+            )
+            .await;
+        }
+
         tasks::last_message_id::queue(self.channel.to_string(), self.id.to_string(), is_dm).await;
 
         if let Some(mentions) = &self.mentions {
@@ -316,6 +343,16 @@ impl Message {
             }
         }
 
+        if let Some(components) = &data.components {
+            if let MessageAuthor::User(user) = &author {
+                if user.bot.is_none() {
+                    return Err(Error::IsNotBot);
+                }
+            }
+
+            ActionRow::validate_rows(components, matches!(author, MessageAuthor::Webhook(_)))?;
+        }
+
         let (author_id, webhook) = match &author {
             MessageAuthor::User(user) => (user.id.clone(), None),
             MessageAuthor::Webhook(webhook) => (webhook.id.clone(), Some((*webhook).clone())),
@@ -328,6 +365,7 @@ impl Message {
             channel: channel.id().to_string(),
             masquerade: data.masquerade,
             interactions: data.interactions.unwrap_or_default(),
+            components: data.components.filter(|rows| !rows.is_empty()),
             author: author_id,
             webhook: webhook.map(|w| w.into()),
             ..Default::default()
@@ -367,10 +405,22 @@ impl Message {
 
         let mut attachments = vec![];
 
+        // Webhooks cannot upload files, so their attachments are not tied to an uploader.
+        let uploader_id = match &author {
+            MessageAuthor::User(user) => Some(user.id.clone()),
+            _ => None,
+        };
+
         for attachment_id in data.attachments.as_deref().unwrap_or_default() {
             attachments.push(
-                db.find_and_use_attachment(attachment_id, "attachments", "message", &message_id)
-                    .await?,
+                db.find_and_use_attachment(
+                    attachment_id,
+                    "attachments",
+                    "message",
+                    &message_id,
+                    uploader_id.as_deref(),
+                )
+                .await?,
             );
         }
 
@@ -379,7 +429,9 @@ impl Message {
         }
 
         for sendable_embed in data.embeds.unwrap_or_default() {
-            message.attach_sendable_embed(db, sendable_embed).await?;
+            message
+                .attach_sendable_embed(db, sendable_embed, uploader_id.as_deref())
+                .await?;
         }
 
         message.content = data.content;
@@ -426,10 +478,11 @@ impl Message {
         &mut self,
         db: &Database,
         embed: SendableEmbed,
+        uploader_id: Option<&str>,
     ) -> Result<()> {
         let media: Option<File> = if let Some(id) = embed.media {
             Some(
-                db.find_and_use_attachment(&id, "attachments", "message", &self.id)
+                db.find_and_use_attachment(&id, "attachments", "message", &self.id, uploader_id)
                     .await?,
             )
         } else {
@@ -453,6 +506,344 @@ impl Message {
 
         Ok(())
     }
+
+    /// Use a component attached to this message, creating an interaction for the owning bot
+    pub async fn interact(
+        &self,
+        db: &Database,
+        user: &User,
+        custom_id: String,
+        values: Vec<String>,
+    ) -> Result<MessageInteraction> {
+        let component = self
+            .components
+            .iter()
+            .flatten()
+            .flat_map(|row| row.components.iter())
+            .find(|component| component.custom_id() == Some(custom_id.as_str()))
+            .ok_or(Error::NotFound)?;
+
+        match component {
+            MessageComponent::Button { disabled, .. } => {
+                if *disabled || !values.is_empty() {
+                    return Err(Error::InvalidOperation);
+                }
+            }
+            MessageComponent::SelectMenu {
+                options,
+                min_values,
+                max_values,
+                disabled,
+                ..
+            } => {
+                if *disabled
+                    || values.len() < min_values.unwrap_or(1)
+                    || values.len() > max_values.unwrap_or(1)
+                    || values.iter().collect::<HashSet<&String>>().len() != values.len()
+                {
+                    return Err(Error::InvalidOperation);
+                }
+
+                for value in &values {
+                    if !options.iter().any(|option| &option.value == value) {
+                        return Err(Error::InvalidProperty);
+                    }
+                }
+            }
+        }
+
+        // Only messages sent by bots have someone to deliver the interaction to
+        let bot = db
+            .fetch_bot(&self.author)
+            .await
+            .map_err(|_| Error::InvalidOperation)?;
+
+        let interaction = MessageInteraction {
+            id: Ulid::new().to_string(),
+            channel_id: self.channel.to_string(),
+            message_id: self.id.to_string(),
+            user_id: user.id.to_string(),
+            bot_id: bot.id,
+            custom_id,
+            values,
+        };
+
+        interaction.create().await?;
+        Ok(interaction)
+    }
+}
+
+impl MessageComponent {
+    pub fn custom_id(&self) -> Option<&str> {
+        match self {
+            MessageComponent::Button { custom_id, .. } => custom_id.as_deref(),
+            MessageComponent::SelectMenu { custom_id, .. } => Some(custom_id),
+        }
+    }
+}
+
+impl ActionRow {
+    /// Validate a set of action rows
+    ///
+    /// Webhooks have no bot to deliver interactions to, so may only attach link buttons.
+    pub fn validate_rows(rows: &[ActionRow], links_only: bool) -> Result<()> {
+        if rows.len() > 5 {
+            return Err(Error::InvalidProperty);
+        }
+
+        let mut custom_ids = HashSet::new();
+        let mut check_custom_id = |id: &str| {
+            if id.is_empty() || id.len() > 100 || !custom_ids.insert(id.to_string()) {
+                Err(Error::InvalidProperty)
+            } else {
+                Ok(())
+            }
+        };
+
+        for row in rows {
+            if row.components.is_empty() || row.components.len() > 5 {
+                return Err(Error::InvalidProperty);
+            }
+
+            for component in &row.components {
+                match component {
+                    MessageComponent::Button {
+                        custom_id,
+                        label,
+                        style,
+                        emoji,
+                        url,
+                        ..
+                    } => {
+                        if label.is_none() && emoji.is_none() {
+                            return Err(Error::InvalidProperty);
+                        }
+
+                        if label.as_ref().is_some_and(|v| v.is_empty() || v.len() > 80) {
+                            return Err(Error::InvalidProperty);
+                        }
+
+                        if style == &ButtonStyle::Link {
+                            if custom_id.is_some()
+                                || url.as_ref().map_or(true, |v| v.is_empty() || v.len() > 512)
+                            {
+                                return Err(Error::InvalidProperty);
+                            }
+                        } else {
+                            if links_only || url.is_some() {
+                                return Err(Error::InvalidProperty);
+                            }
+
+                            check_custom_id(custom_id.as_deref().unwrap_or_default())?;
+                        }
+                    }
+                    MessageComponent::SelectMenu {
+                        custom_id,
+                        placeholder,
+                        options,
+                        min_values,
+                        max_values,
+                        ..
+                    } => {
+                        // Select menus take up a whole row
+                        if links_only || row.components.len() != 1 {
+                            return Err(Error::InvalidProperty);
+                        }
+
+                        check_custom_id(custom_id)?;
+
+                        if placeholder.as_ref().is_some_and(|v| v.len() > 150)
+                            || options.is_empty()
+                            || options.len() > 25
+                        {
+                            return Err(Error::InvalidProperty);
+                        }
+
+                        let (min, max) = (min_values.unwrap_or(1), max_values.unwrap_or(1));
+                        if min > max || max == 0 || max > options.len() {
+                            return Err(Error::InvalidProperty);
+                        }
+
+                        let mut values = HashSet::new();
+                        for option in options {
+                            if option.label.is_empty()
+                                || option.label.len() > 100
+                                || option.value.is_empty()
+                                || option.value.len() > 100
+                                || !values.insert(&option.value)
+                            {
+                                return Err(Error::InvalidProperty);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl MessageInteraction {
+    fn key(id: &str) -> String {
+        format!("interaction:{id}")
+    }
+
+    /// Store the interaction and deliver it to the owning bot
+    pub async fn create(&self) -> Result<()> {
+        let payload = serde_json::to_string(self).map_err(|_| Error::InternalError)?;
+        let mut conn = get_connection().await.map_err(|_| Error::InternalError)?;
+
+        conn.set_ex::<_, _, ()>(Self::key(&self.id), payload, INTERACTION_TTL)
+            .await
+            .map_err(|_| Error::InternalError)?;
+
+        EventV1::InteractionCreate(self.clone())
+            .private(self.bot_id.clone())
+            .await;
+
+        Ok(())
+    }
+
+    /// Fetch a pending interaction which has not yet expired
+    pub async fn fetch(id: &str) -> Result<MessageInteraction> {
+        let mut conn = get_connection().await.map_err(|_| Error::InternalError)?;
+
+        conn.get::<_, Option<String>>(Self::key(id))
+            .await
+            .map_err(|_| Error::InternalError)?
+            .and_then(|payload| serde_json::from_str(&payload).ok())
+            .ok_or(Error::NotFound)
+    }
+
+    /// Remove this interaction so it cannot be answered again
+    ///
+    /// Fails if the interaction expired or was already answered.
+    async fn consume(&self) -> Result<()> {
+        let mut conn = get_connection().await.map_err(|_| Error::InternalError)?;
+
+        conn.get_del::<_, Option<String>>(Self::key(&self.id))
+            .await
+            .map_err(|_| Error::InternalError)?
+            .map(|_| ())
+            .ok_or(Error::NotFound)
+    }
+
+    /// Answer this interaction on behalf of the bot
+    ///
+    /// Each interaction may only be answered once.
+    pub async fn respond(&self, db: &Database, response: InteractionResponse) -> Result<()> {
+        match response {
+            InteractionResponse::UpdateMessage {
+                content,
+                embeds,
+                components,
+            } => {
+                let embeds = validate_response(&content, embeds, &components)?;
+                let mut message = db.fetch_message(&self.message_id).await?;
+                self.consume().await?;
+
+                let mut partial = PartialMessage {
+                    edited: Some(Timestamp::now_utc()),
+                    content,
+                    components,
+                    ..Default::default()
+                };
+
+                if let Some(embeds) = embeds {
+                    let mut new_embeds = vec![];
+                    for embed in embeds {
+                        new_embeds.push(
+                            embed
+                                .into_embed(db, message.id.clone(), Some(&self.bot_id))
+                                .await?,
+                        );
+                    }
+
+                    partial.embeds = Some(new_embeds);
+                }
+
+                message.update(db, partial).await
+            }
+            InteractionResponse::EphemeralReply {
+                content,
+                embeds,
+                components,
+            } => {
+                let embeds = validate_response(&content, embeds, &components)?;
+
+                if content.as_ref().map_or(true, |v| v.is_empty())
+                    && embeds.as_ref().map_or(true, |v| v.is_empty())
+                {
+                    return Err(Error::EmptyMessage);
+                }
+
+                // Ephemeral messages are never stored, so they cannot link attachments
+                let embeds = embeds
+                    .map(|embeds| {
+                        embeds
+                            .into_iter()
+                            .map(|embed| {
+                                if embed.media.is_some() {
+                                    Err(Error::InvalidProperty)
+                                } else {
+                                    Ok(Embed::Text(Text {
+                                        icon_url: embed.icon_url,
+                                        url: embed.url,
+                                        title: embed.title,
+                                        description: embed.description,
+                                        media: None,
+                                        colour: embed.colour,
+                                    }))
+                                }
+                            })
+                            .collect::<Result<Vec<Embed>>>()
+                    })
+                    .transpose()?;
+
+                self.consume().await?;
+
+                EventV1::EphemeralMessage {
+                    interaction_id: self.id.to_string(),
+                    message: Message {
+                        id: Ulid::new().to_string(),
+                        channel: self.channel_id.to_string(),
+                        author: self.bot_id.to_string(),
+                        content,
+                        embeds,
+                        components,
+                        ..Default::default()
+                    },
+                }
+                .private(self.user_id.clone())
+                .await;
+
+                Ok(())
+            }
+        }
+    }
+}
+
+fn validate_response(
+    content: &Option<String>,
+    embeds: Option<Vec<SendableEmbed>>,
+    components: &Option<Vec<ActionRow>>,
+) -> Result<Option<Vec<SendableEmbed>>> {
+    if let Some(embeds) = &embeds {
+        for embed in embeds {
+            embed
+                .validate()
+                .map_err(|error| Error::FailedValidation { error })?;
+        }
+    }
+
+    Message::validate_sum(content, embeds.as_deref().unwrap_or_default())?;
+
+    if let Some(rows) = components {
+        ActionRow::validate_rows(rows, false)?;
+    }
+
+    Ok(embeds)
 }
 
 pub trait IntoUsers {
@@ -568,10 +959,15 @@ impl Interactions {
 }
 
 impl SendableEmbed {
-    pub async fn into_embed(self, db: &Database, message_id: String) -> Result<Embed> {
+    pub async fn into_embed(
+        self,
+        db: &Database,
+        message_id: String,
+        uploader_id: Option<&str>,
+    ) -> Result<Embed> {
         let media = if let Some(id) = self.media {
             Some(
-                db.find_and_use_attachment(&id, "attachments", "message", &message_id)
+                db.find_and_use_attachment(&id, "attachments", "message", &message_id, uploader_id)
                     .await?,
             )
         } else {
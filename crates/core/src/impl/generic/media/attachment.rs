@@ -1,38 +1,141 @@
-use crate::{database::Database, models::File, Result};
+use ulid::Ulid;
+
+use crate::{
+    database::Database,
+    models::{attachment::Metadata, File},
+    storage::{extract_metadata, file_storage, sniff_content_type, tag_limits},
+    Error, Result,
+};
 
 impl File {
-    pub async fn use_attachment(db: &Database, id: &str, parent: &str) -> Result<File> {
-        db.find_and_use_attachment(id, "attachments", "message", parent)
+    pub async fn use_attachment(
+        db: &Database,
+        id: &str,
+        parent: &str,
+        uploader_id: &str,
+    ) -> Result<File> {
+        db.find_and_use_attachment(id, "attachments", "message", parent, Some(uploader_id))
             .await
     }
 
-    pub async fn use_background(db: &Database, id: &str, parent: &str) -> Result<File> {
-        db.find_and_use_attachment(id, "backgrounds", "user", parent)
+    pub async fn use_background(
+        db: &Database,
+        id: &str,
+        parent: &str,
+        uploader_id: &str,
+    ) -> Result<File> {
+        db.find_and_use_attachment(id, "backgrounds", "user", parent, Some(uploader_id))
             .await
     }
 
-    pub async fn use_avatar(db: &Database, id: &str, parent: &str) -> Result<File> {
-        db.find_and_use_attachment(id, "avatars", "user", parent)
+    pub async fn use_avatar(
+        db: &Database,
+        id: &str,
+        parent: &str,
+        uploader_id: &str,
+    ) -> Result<File> {
+        db.find_and_use_attachment(id, "avatars", "user", parent, Some(uploader_id))
             .await
     }
 
-    pub async fn use_icon(db: &Database, id: &str, parent: &str) -> Result<File> {
-        db.find_and_use_attachment(id, "icons", "object", parent)
+    pub async fn use_icon(
+        db: &Database,
+        id: &str,
+        parent: &str,
+        uploader_id: &str,
+    ) -> Result<File> {
+        db.find_and_use_attachment(id, "icons", "object", parent, Some(uploader_id))
             .await
     }
 
-    pub async fn use_server_icon(db: &Database, id: &str, parent: &str) -> Result<File> {
-        db.find_and_use_attachment(id, "icons", "object", parent)
+    pub async fn use_server_icon(
+        db: &Database,
+        id: &str,
+        parent: &str,
+        uploader_id: &str,
+    ) -> Result<File> {
+        db.find_and_use_attachment(id, "icons", "object", parent, Some(uploader_id))
             .await
     }
 
-    pub async fn use_banner(db: &Database, id: &str, parent: &str) -> Result<File> {
-        db.find_and_use_attachment(id, "banners", "server", parent)
+    pub async fn use_banner(
+        db: &Database,
+        id: &str,
+        parent: &str,
+        uploader_id: &str,
+    ) -> Result<File> {
+        db.find_and_use_attachment(id, "banners", "server", parent, Some(uploader_id))
             .await
     }
 
-    pub async fn use_emoji(db: &Database, id: &str, parent: &str) -> Result<File> {
-        db.find_and_use_attachment(id, "emojis", "object", parent)
+    pub async fn use_emoji(
+        db: &Database,
+        id: &str,
+        parent: &str,
+        uploader_id: &str,
+    ) -> Result<File> {
+        db.find_and_use_attachment(id, "emojis", "object", parent, Some(uploader_id))
             .await
     }
+
+    /// Store an uploaded file under the given tag, ready to be used by its ID
+    ///
+    /// Only the uploader may use the file.
+    pub async fn upload(
+        db: &Database,
+        tag: &str,
+        filename: &str,
+        data: Vec<u8>,
+        uploader_id: &str,
+    ) -> Result<File> {
+        let limits = tag_limits(tag).ok_or(Error::UnknownTag)?;
+        if data.len() > limits.max_size {
+            return Err(Error::FileTooLarge {
+                max: limits.max_size,
+            });
+        }
+
+        let content_type = sniff_content_type(&data);
+        let metadata = extract_metadata(content_type, &data);
+        if limits.images_only && !matches!(metadata, Metadata::Image { .. }) {
+            return Err(Error::FileTypeNotAllowed);
+        }
+
+        let file = File {
+            id: Ulid::new().to_string(),
+            tag: tag.to_string(),
+            filename: sanitise_filename(filename),
+            metadata,
+            content_type: content_type.to_string(),
+            size: data.len() as isize,
+            uploader_id: Some(uploader_id.to_string()),
+            ..Default::default()
+        };
+
+        file_storage()
+            .put(&file.tag, &file.id, data, &file.content_type)
+            .await?;
+
+        db.insert_attachment(&file).await?;
+        Ok(file)
+    }
+}
+
+/// Strip any path and control characters from a client-provided filename
+fn sanitise_filename(filename: &str) -> String {
+    let name: String = filename
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(128)
+        .collect();
+
+    let name = name.trim();
+    if name.is_empty() || name == "." || name == ".." {
+        "file".to_string()
+    } else {
+        name.to_string()
+    }
 }
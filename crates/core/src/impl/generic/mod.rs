@@ -1,4 +1,5 @@
 pub mod admin {
+    pub mod admin_action;
     pub mod migrations;
 }
 
@@ -28,6 +29,7 @@ pub mod users {
 
 pub mod safety {
     pub mod report;
+    pub mod server_report;
     pub mod snapshot;
 }
 
@@ -36,5 +38,6 @@ pub mod ratelimiter {
 }
 
 pub mod webhooks {
+    pub mod outbound_webhook;
     pub mod webhook;
 }
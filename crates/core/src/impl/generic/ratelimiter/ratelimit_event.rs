@@ -1,10 +1,105 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+
+use once_cell::sync::Lazy;
 use ulid::Ulid;
 
 use crate::{
     models::ratelimit_events::ratelimit::{RatelimitEvent, RatelimitEventType},
-    Database, Result,
+    variables::delta::MONO_CHAT_COOLDOWNS,
+    Database, Error, Result,
 };
 
+/// Number of times each action may be taken within its window
+///
+/// Defaults may be overridden with `MONO_CHAT_COOLDOWNS`, given as
+/// comma separated `Action=count/seconds` pairs, e.g. `ServerCreate=5/3600`.
+static COOLDOWNS: Lazy<HashMap<RatelimitEventType, (usize, Duration)>> = Lazy::new(|| {
+    let mut cooldowns = HashMap::from([
+        (
+            RatelimitEventType::DiscriminatorChange,
+            (1, Duration::from_secs(60 * 60)),
+        ),
+        (
+            RatelimitEventType::UsernameChange,
+            (2, Duration::from_secs(60 * 60)),
+        ),
+        (
+            RatelimitEventType::ServerCreate,
+            (5, Duration::from_secs(60 * 60)),
+        ),
+        (
+            RatelimitEventType::FriendRequest,
+            (20, Duration::from_secs(10 * 60)),
+        ),
+        (
+            RatelimitEventType::GroupCreate,
+            (10, Duration::from_secs(60 * 60)),
+        ),
+        (
+            RatelimitEventType::InviteCreate,
+            (20, Duration::from_secs(60 * 60)),
+        ),
+        (
+            RatelimitEventType::ActivityChange,
+            (10, Duration::from_secs(60)),
+        ),
+    ]);
+
+    for pair in MONO_CHAT_COOLDOWNS
+        .split(',')
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+    {
+        match RatelimitEventType::parse_cooldown(pair) {
+            Some((event_type, cooldown)) => {
+                cooldowns.insert(event_type, cooldown);
+            }
+            None => warn!("Ignoring invalid cooldown configuration `{pair}`."),
+        }
+    }
+
+    cooldowns
+});
+
+impl RatelimitEventType {
+    /// Parse a single `Action=count/seconds` pair
+    ///
+    /// The count must be at least one.
+    fn parse_cooldown(pair: &str) -> Option<(RatelimitEventType, (usize, Duration))> {
+        let (name, limit) = pair.split_once('=')?;
+        let (count, seconds) = limit.split_once('/')?;
+
+        let count: usize = count.trim().parse().ok()?;
+        if count == 0 {
+            return None;
+        }
+
+        let event_type = match name.trim() {
+            "DiscriminatorChange" => RatelimitEventType::DiscriminatorChange,
+            "UsernameChange" => RatelimitEventType::UsernameChange,
+            "ServerCreate" => RatelimitEventType::ServerCreate,
+            "FriendRequest" => RatelimitEventType::FriendRequest,
+            "GroupCreate" => RatelimitEventType::GroupCreate,
+            "InviteCreate" => RatelimitEventType::InviteCreate,
+            "ActivityChange" => RatelimitEventType::ActivityChange,
+            _ => return None,
+        };
+
+        Some((
+            event_type,
+            (count, Duration::from_secs(seconds.trim().parse().ok()?)),
+        ))
+    }
+
+    /// Number of times this action may be taken within its window, and the window length
+    pub fn cooldown(&self) -> (usize, Duration) {
+        COOLDOWNS[self]
+    }
+}
+
 impl RatelimitEvent {
     pub async fn create(
         db: &Database,
@@ -18,4 +113,35 @@ impl RatelimitEvent {
         })
         .await
     }
+
+    /// Check whether a target has exhausted an action within its window,
+    /// returning how long until it becomes available again
+    pub async fn throw_if_on_cooldown(
+        db: &Database,
+        target_id: &str,
+        event_type: RatelimitEventType,
+    ) -> Result<()> {
+        let (count, period) = event_type.cooldown();
+        let events = db
+            .fetch_ratelimit_events(target_id, event_type, period)
+            .await?;
+
+        if events.len() < count {
+            return Ok(());
+        }
+
+        // The action frees up once enough of the oldest events leave the window
+        let retry_after = Ulid::from_string(&events[events.len() - count].id)
+            .ok()
+            .and_then(|id| {
+                (id.datetime() + period)
+                    .duration_since(SystemTime::now())
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        Err(Error::OnCooldown {
+            retry_after: retry_after.as_millis() as u64,
+        })
+    }
 }
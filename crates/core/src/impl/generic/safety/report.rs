@@ -1,13 +1,45 @@
 use crate::{
     database::Database,
-    models::{report::PartialReport, Report},
+    events::client::EventV1,
+    models::{
+        report::{FieldsReport, PartialReport},
+        Report,
+    },
     Result,
 };
 
 impl Report {
     /// Update report data
-    pub async fn update(&mut self, db: &Database, partial: PartialReport) -> Result<()> {
+    ///
+    /// The reporter is notified if the status changes.
+    pub async fn update(
+        &mut self,
+        db: &Database,
+        partial: PartialReport,
+        remove: Vec<FieldsReport>,
+    ) -> Result<()> {
+        for field in &remove {
+            self.remove_field(field);
+        }
+
         self.apply_options(partial.clone());
-        db.update_report(&self.id, &partial).await
+        db.update_report(&self.id, &partial, remove).await?;
+
+        if let Some(status) = partial.status {
+            EventV1::ReportUpdate {
+                id: self.id.clone(),
+                status,
+            }
+            .private(self.author_id.clone())
+            .await;
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_field(&mut self, field: &FieldsReport) {
+        match field {
+            FieldsReport::AssignedModeratorId => self.assigned_moderator_id = None,
+        }
     }
 }
@@ -0,0 +1,145 @@
+use ulid::Ulid;
+
+use crate::{
+    events::client::EventV1,
+    models::{
+        server_report::{
+            DataCreateServerReport, PartialServerReport, ServerReportAction, ServerReportStatus,
+            ServerReportedContent,
+        },
+        snapshot::SnapshotContent,
+        Channel, Message, Server, ServerReport, Snapshot, User,
+    },
+    permissions::defn::ChannelPermission,
+    perms, Database, Error, Result,
+};
+
+impl ServerReport {
+    /// File a report with a server's moderators, capturing the reported content
+    pub async fn create(
+        db: &Database,
+        server: &Server,
+        author: &User,
+        data: DataCreateServerReport,
+    ) -> Result<ServerReport> {
+        db.fetch_member(&server.id, &author.id).await?;
+
+        let (reported_user_id, snapshots) = match &data.content {
+            ServerReportedContent::Message { id } => {
+                let message = ServerReport::fetch_message(db, server, author, id).await?;
+                let reported_user_id = message.author.to_string();
+                let (snapshot, _) = SnapshotContent::generate_from_message(db, message).await?;
+                (reported_user_id, vec![snapshot])
+            }
+            ServerReportedContent::Member { id, message_id } => {
+                db.fetch_member(&server.id, id).await?;
+
+                let (snapshot, _) = SnapshotContent::generate_from_user(db.fetch_user(id).await?)?;
+                let mut snapshots = vec![snapshot];
+
+                if let Some(message_id) = message_id {
+                    let message =
+                        ServerReport::fetch_message(db, server, author, message_id).await?;
+
+                    if &message.author != id {
+                        return Err(Error::InvalidOperation);
+                    }
+
+                    let (snapshot, _) = SnapshotContent::generate_from_message(db, message).await?;
+                    snapshots.push(snapshot);
+                }
+
+                (id.to_string(), snapshots)
+            }
+        };
+
+        if reported_user_id == author.id {
+            return Err(Error::CannotReportYourself);
+        }
+
+        let report = ServerReport {
+            id: Ulid::new().to_string(),
+            server_id: server.id.to_string(),
+            author_id: author.id.to_string(),
+            content: data.content,
+            reported_user_id,
+            additional_context: data.additional_context,
+            status: ServerReportStatus::Open,
+            moderator_id: None,
+            action: None,
+        };
+
+        for content in snapshots {
+            db.insert_snapshot(&Snapshot {
+                id: Ulid::new().to_string(),
+                report_id: report.id.to_string(),
+                content,
+            })
+            .await?;
+        }
+
+        db.insert_server_report(&report).await?;
+
+        // Reporters stay anonymous to integrations
+        let mut anonymous = report.clone();
+        anonymous.author_id = String::new();
+
+        crate::tasks::outbound_webhooks::queue(
+            Some(server.id.to_string()),
+            None,
+            EventV1::ServerReportCreate(anonymous),
+        )
+        .await;
+
+        Ok(report)
+    }
+
+    /// Fetch a message in this server which the reporter is able to see
+    async fn fetch_message(
+        db: &Database,
+        server: &Server,
+        author: &User,
+        id: &str,
+    ) -> Result<Message> {
+        let message = db.fetch_message(id).await?;
+        let channel = db.fetch_channel(&message.channel).await?;
+
+        match &channel {
+            Channel::TextChannel {
+                server: server_id, ..
+            }
+            | Channel::VoiceChannel {
+                server: server_id, ..
+            } if server_id == &server.id => {}
+            _ => return Err(Error::NotFound),
+        }
+
+        perms(author)
+            .channel(&channel)
+            .throw_permission(db, ChannelPermission::ViewChannel)
+            .await?;
+
+        Ok(message)
+    }
+
+    /// Close this report, recording the action taken
+    pub async fn close(
+        &mut self,
+        db: &Database,
+        moderator_id: &str,
+        action: ServerReportAction,
+    ) -> Result<()> {
+        let partial = PartialServerReport {
+            status: Some(match action {
+                ServerReportAction::Dismiss => ServerReportStatus::Dismissed,
+                _ => ServerReportStatus::Actioned,
+            }),
+            moderator_id: Some(moderator_id.to_string()),
+            action: Some(action),
+            ..Default::default()
+        };
+
+        self.apply_options(partial.clone());
+        db.update_server_report(&self.id, &partial).await
+    }
+}
@@ -1,12 +1,78 @@
+use std::collections::HashSet;
+
 use crate::{
     models::{
         message::{MessageFilter, MessageQuery, MessageSort, MessageTimePeriod},
-        snapshot::SnapshotContent,
-        Message, Server, User,
+        snapshot::{SnapshotContent, SnapshotWithContext},
+        Channel, Message, Server, Snapshot, User,
     },
     Database, Result,
 };
 
+impl Snapshot {
+    /// Attach the users, channels and server referenced by this snapshot
+    pub async fn with_context(self, db: &Database) -> Result<SnapshotWithContext> {
+        let mut user_ids = HashSet::new();
+        let mut channel_ids = HashSet::new();
+
+        match &self.content {
+            SnapshotContent::Message {
+                prior_context,
+                leading_context,
+                message,
+            } => {
+                for message in prior_context
+                    .iter()
+                    .chain(leading_context.iter())
+                    .chain(std::iter::once(message))
+                {
+                    user_ids.insert(message.author.to_string());
+                }
+
+                channel_ids.insert(message.channel.to_string());
+            }
+            SnapshotContent::Server(server) => {
+                user_ids.insert(server.owner.to_string());
+            }
+            SnapshotContent::User(_) => {}
+        }
+
+        let users = if user_ids.is_empty() {
+            vec![]
+        } else {
+            db.fetch_users(&user_ids.into_iter().collect::<Vec<String>>())
+                .await?
+                .into_iter()
+                .map(|user| user.foreign())
+                .collect()
+        };
+
+        let channels = if channel_ids.is_empty() {
+            vec![]
+        } else {
+            db.fetch_channels(&channel_ids.into_iter().collect::<Vec<String>>())
+                .await?
+        };
+
+        let server = match channels.iter().find_map(|channel| match channel {
+            Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
+                Some(server)
+            }
+            _ => None,
+        }) {
+            Some(server) => db.fetch_server(server).await.ok(),
+            None => None,
+        };
+
+        Ok(SnapshotWithContext {
+            snapshot: self,
+            users,
+            channels,
+            server,
+        })
+    }
+}
+
 impl SnapshotContent {
     pub async fn generate_from_message(
         db: &Database,
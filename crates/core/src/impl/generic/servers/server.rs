@@ -184,12 +184,13 @@ impl Server {
             }
         }
 
-        EventV1::ServerMemberJoin {
+        let event = EventV1::ServerMemberJoin {
             id: self.id.clone(),
             user: user.id.clone(),
-        }
-        .p(self.id.clone())
-        .await;
+        };
+
+        event.clone().p(self.id.clone()).await;
+        crate::tasks::outbound_webhooks::queue(Some(self.id.clone()), None, event).await;
 
         EventV1::ServerCreate {
             id: self.id.clone(),
@@ -225,12 +226,13 @@ impl Server {
     ) -> Result<()> {
         db.delete_member(&member.id).await?;
 
-        EventV1::ServerMemberLeave {
+        let event = EventV1::ServerMemberLeave {
             id: self.id.to_string(),
             user: member.id.user.clone(),
-        }
-        .p(member.id.server)
-        .await;
+        };
+
+        event.clone().p(member.id.server).await;
+        crate::tasks::outbound_webhooks::queue(Some(self.id.clone()), None, event).await;
 
         if !silent {
             if let Some(id) = self.system_messages.as_ref().and_then(|x| match intention {
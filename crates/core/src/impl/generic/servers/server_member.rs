@@ -13,6 +13,10 @@ use crate::{
     Error, Result,
 };
 use iso8601_timestamp::Timestamp;
+use std::time::Duration;
+
+/// Longest a member may be timed out for
+const MAX_TIMEOUT: Duration = Duration::from_secs(28 * 24 * 60 * 60);
 
 impl Default for Member {
     fn default() -> Self {
@@ -37,6 +41,16 @@ impl Member {
         }
     }
 
+    /// Check that a timeout ends in the future and within the maximum timeout length
+    pub fn validate_timeout(timeout: &Timestamp) -> Result<()> {
+        let now = *Timestamp::now_utc();
+        if **timeout <= now || **timeout > now + MAX_TIMEOUT {
+            Err(Error::InvalidProperty)
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn in_timeout(&self) -> bool {
         if let Some(timeout) = self.timeout {
             *timeout > *Timestamp::now_utc()
@@ -129,12 +143,13 @@ impl Member {
 
         // let emojis = db.fetch_emoji_by_parent_id(&server.id).await?;
 
-        EventV1::ServerMemberJoin {
+        let event = EventV1::ServerMemberJoin {
             id: server.id.clone(),
             user: user.id.clone(),
-        }
-        .p(server.id.clone())
-        .await;
+        };
+
+        event.clone().p(server.id.clone()).await;
+        crate::tasks::outbound_webhooks::queue(Some(server.id.clone()), None, event).await;
 
         EventV1::ServerCreate {
             id: server.id.clone(),
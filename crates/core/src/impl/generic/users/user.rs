@@ -2,7 +2,7 @@ use crate::{
     database::Database,
     events::client::EventV1,
     models::{
-        user::{FieldsUser, PartialUser, Presence, RelationshipStatus, UserHint},
+        user::{FieldsUser, PartialUser, Presence, RelationshipStatus, UserActivity, UserHint},
         User,
     },
     permissions::{
@@ -19,6 +19,7 @@ use crate::{
 use futures::try_join;
 use redis_kiss::{get_connection, AsyncCommands};
 use ulid::Ulid;
+use validator::Validate;
 
 impl User {
     pub async fn update<'a>(
@@ -54,11 +55,26 @@ impl User {
                     x.text = None;
                 }
             }
+            FieldsUser::StatusEmoji => {
+                if let Some(x) = self.status.as_mut() {
+                    x.emoji = None;
+                }
+            }
             FieldsUser::StatusPresence => {
                 if let Some(x) = self.status.as_mut() {
                     x.presence = None;
                 }
             }
+            FieldsUser::StatusActivity => {
+                if let Some(x) = self.status.as_mut() {
+                    x.activity = None;
+                }
+            }
+            FieldsUser::StatusExpiresAt => {
+                if let Some(x) = self.status.as_mut() {
+                    x.expires_at = None;
+                }
+            }
             FieldsUser::ProfileContent => {
                 if let Some(x) = self.profile.as_mut() {
                     x.content = None;
@@ -72,6 +88,59 @@ impl User {
         }
     }
 
+    /// Set or clear the activity shown on this user's status
+    pub async fn set_activity(
+        &mut self,
+        db: &Database,
+        activity: Option<UserActivity>,
+    ) -> Result<()> {
+        if let Some(activity) = activity {
+            activity
+                .validate()
+                .map_err(|error| Error::FailedValidation { error })?;
+
+            let mut status = self.status.clone().unwrap_or_default();
+            status.activity = Some(activity);
+
+            self.update(
+                db,
+                PartialUser {
+                    status: Some(status),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+        } else {
+            self.update(db, Default::default(), vec![FieldsUser::StatusActivity])
+                .await
+        }
+    }
+
+    /// Clear the parts of this user's status which are set to expire
+    pub async fn clear_expired_status(&mut self, db: &Database) -> Result<()> {
+        self.update(
+            db,
+            Default::default(),
+            vec![
+                FieldsUser::StatusText,
+                FieldsUser::StatusEmoji,
+                FieldsUser::StatusActivity,
+                FieldsUser::StatusExpiresAt,
+            ],
+        )
+        .await
+    }
+
+    /// Ensure this user may use the platform administration API
+    pub fn throw_if_not_privileged(&self) -> Result<()> {
+        if self.privileged {
+            Ok(())
+        } else {
+            Err(Error::NotPrivileged)
+        }
+    }
+
     pub async fn has_mutual_connection(&self, db: &Database, user_b: &str) -> Result<bool> {
         Ok(!db
             .fetch_mutual_server_ids(&self.id, user_b)
@@ -96,6 +165,7 @@ impl User {
                 if presence == &Presence::Invisible {
                     self.status = None;
                     self.online = false;
+                    self.online_presence = None;
                 }
             }
         }
@@ -463,6 +533,7 @@ impl User {
             bot: self.bot,
             relationship,
             online: can_see_profile && is_online(&self.id).await,
+            online_presence: None,
             id: self.id,
         }
     }
@@ -481,6 +552,7 @@ impl User {
             bot: self.bot,
             relationship: RelationshipStatus::User,
             online: is_online(&self.id).await,
+            online_presence: None,
             id: self.id,
         }
     }
@@ -502,6 +574,7 @@ impl Default for User {
             privileged: Default::default(),
             bot: Default::default(),
             online: Default::default(),
+            online_presence: Default::default(),
             relationship: RelationshipStatus::None,
         }
     }
@@ -0,0 +1,83 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{
+    events::client::EventV1,
+    models::outbound_webhook::{
+        FieldsOutboundWebhook, OutboundEventType, OutboundWebhook, PartialOutboundWebhook,
+    },
+    Database, Result,
+};
+
+impl OutboundWebhook {
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_outbound_webhook(self).await
+    }
+
+    pub async fn update(
+        &mut self,
+        db: &Database,
+        partial: PartialOutboundWebhook,
+        remove: Vec<FieldsOutboundWebhook>,
+    ) -> Result<()> {
+        for field in &remove {
+            self.remove_field(field)
+        }
+
+        self.apply_options(partial.clone());
+
+        db.update_outbound_webhook(&self.id, &partial, &remove)
+            .await
+    }
+
+    pub fn remove_field(&mut self, field: &FieldsOutboundWebhook) {
+        match field {
+            FieldsOutboundWebhook::ChannelId => self.channel_id = None,
+        }
+    }
+
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_outbound_webhook(&self.id).await
+    }
+
+    /// Whether this subscription wants an event of the given type from the given channel
+    pub fn wants(&self, event_type: OutboundEventType, channel_id: Option<&str>) -> bool {
+        self.enabled
+            && self.events.contains(&event_type)
+            && match (&self.channel_id, channel_id) {
+                (Some(scope), Some(channel_id)) => scope == channel_id,
+                (Some(_), None) => false,
+                (None, _) => true,
+            }
+    }
+
+    /// Compute the signature sent alongside a delivery
+    ///
+    /// Receivers should compute HMAC-SHA256 over `{timestamp}.{body}` with the
+    /// webhook secret and compare it against the `X-Signature-256` header.
+    pub fn sign(secret: &str, timestamp: u64, body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(body.as_bytes());
+
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
+impl OutboundEventType {
+    /// Determine which outbound event type, if any, an event corresponds to
+    pub fn from_event(event: &EventV1) -> Option<OutboundEventType> {
+        Some(match event {
+            EventV1::ServerMemberJoin { .. } => OutboundEventType::MemberJoin,
+            EventV1::ServerMemberLeave { .. } => OutboundEventType::MemberLeave,
+            EventV1::Message(_) => OutboundEventType::MessageCreate,
+            EventV1::MessageUpdate { .. } => OutboundEventType::MessageUpdate,
+            EventV1::MessageDelete { .. } => OutboundEventType::MessageDelete,
+            EventV1::ServerReportCreate(_) => OutboundEventType::ReportCreate,
+            _ => return None,
+        })
+    }
+}
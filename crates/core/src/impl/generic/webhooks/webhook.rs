@@ -1,6 +1,10 @@
 use crate::{
     events::client::EventV1,
-    models::channel::{FieldsWebhook, PartialWebhook, Webhook},
+    models::{
+        channel::{FieldsWebhook, PartialWebhook, Webhook},
+        message::DataMessageSend,
+    },
+    permissions::{defn::ChannelPermission, r#impl::PermissionValue},
     Database, Error, Result,
 };
 
@@ -28,6 +32,30 @@ impl Webhook {
         }
     }
 
+    /// Check this webhook has the permissions required to send the given message
+    pub fn throw_if_cannot_send(&self, data: &DataMessageSend) -> Result<()> {
+        let permissions: PermissionValue = self.permissions.into();
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+        if data.attachments.as_ref().map_or(false, |v| !v.is_empty()) {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::UploadFiles)?;
+        }
+
+        if data.embeds.as_ref().map_or(false, |v| !v.is_empty()) {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::SendEmbeds)?;
+        }
+
+        if data.masquerade.is_some() {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::Masquerade)?;
+        }
+
+        if data.interactions.is_some() {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::React)?;
+        }
+
+        Ok(())
+    }
+
     pub async fn update(
         &mut self,
         db: &Database,
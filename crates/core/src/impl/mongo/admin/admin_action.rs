@@ -0,0 +1,43 @@
+use bson::Document;
+use mongodb::options::FindOptions;
+
+use super::super::MongoDb;
+use crate::{models::admin_action::AdminAction, AbstractAdminAction, Result};
+
+static COL: &str = "admin_actions";
+
+#[async_trait]
+impl AbstractAdminAction for MongoDb {
+    async fn insert_admin_action(&self, action: &AdminAction) -> Result<()> {
+        self.insert_one(COL, action).await.map(|_| ())
+    }
+
+    async fn fetch_admin_actions(
+        &self,
+        target_id: Option<&str>,
+        before: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<AdminAction>> {
+        let mut filter = Document::new();
+
+        if let Some(target_id) = target_id {
+            filter.insert("target_id", target_id);
+        }
+
+        if let Some(before) = before {
+            filter.insert("_id", doc! { "$lt": before });
+        }
+
+        self.find_with_option(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(limit)
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+}
@@ -179,6 +179,96 @@ pub async fn create_database(db: &MongoDb) {
     .await
     .expect("Failed to create server_members index.");
 
+    db.run_command(
+        doc! {
+            "createIndexes": "outbound_webhooks",
+            "indexes": [
+                {
+                    "key": {
+                        "server_id": 1_i32
+                    },
+                    "name": "server_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create outbound_webhooks index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "outbound_webhook_deliveries",
+            "indexes": [
+                {
+                    "key": {
+                        "webhook_id": 1_i32,
+                        "_id": -1_i32
+                    },
+                    "name": "webhook_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create outbound_webhook_deliveries index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "admin_actions",
+            "indexes": [
+                {
+                    "key": {
+                        "target_id": 1_i32,
+                        "_id": -1_i32
+                    },
+                    "name": "target_id"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create admin_actions index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_reports",
+            "indexes": [
+                {
+                    "key": {
+                        "server_id": 1_i32,
+                        "status": 1_i32,
+                        "_id": -1_i32
+                    },
+                    "name": "server_id_status"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_reports index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "users",
+            "indexes": [
+                {
+                    "key": {
+                        "status.expires_at": 1_i32
+                    },
+                    "name": "status_expires_at",
+                    "sparse": true
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create users status index.");
+
     db.collection("migrations")
         .insert_one(
             doc! {
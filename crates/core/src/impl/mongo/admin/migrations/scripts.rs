@@ -20,7 +20,7 @@ struct MigrationInfo {
     revision: i32,
 }
 
-pub const LATEST_REVISION: i32 = 21;
+pub const LATEST_REVISION: i32 = 25;
 
 pub async fn migrate_database(db: &MongoDb) {
     let migrations = db.col::<Document>("migrations");
@@ -761,6 +761,117 @@ pub async fn run_migrations(db: &MongoDb, revision: i32) -> i32 {
             .expect("Failed to create safety snapshot index.");
     }
 
+    if revision <= 21 {
+        info!("Running migration [revision 21 / 19-10-2026]: Add outbound webhook indexes.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "outbound_webhooks",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server_id": 1_i32
+                            },
+                            "name": "server_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create outbound webhook index.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "outbound_webhook_deliveries",
+                    "indexes": [
+                        {
+                            "key": {
+                                "webhook_id": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "webhook_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create outbound webhook delivery index.");
+    }
+
+    if revision <= 22 {
+        info!("Running migration [revision 22 / 19-10-2026]: Add admin action log index.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "admin_actions",
+                    "indexes": [
+                        {
+                            "key": {
+                                "target_id": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "target_id"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create admin action index.");
+    }
+
+    if revision <= 23 {
+        info!("Running migration [revision 23 / 19-10-2026]: Add server report index.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_reports",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server_id": 1_i32,
+                                "status": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "server_id_status"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server report index.");
+    }
+
+    if revision <= 24 {
+        info!("Running migration [revision 24 / 19-10-2026]: Add status expiry index.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "users",
+                    "indexes": [
+                        {
+                            "key": {
+                                "status.expires_at": 1_i32
+                            },
+                            "name": "status_expires_at",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create status expiry index.");
+    }
+
     // Need to migrate fields on attachments, change `user_id`, `object_id`, etc to `parent`.
 
     // Reminder to update LATEST_REVISION when adding new migrations.
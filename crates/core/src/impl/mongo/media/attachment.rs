@@ -29,28 +29,41 @@ impl MongoDb {
 
 #[async_trait]
 impl AbstractAttachment for MongoDb {
+    async fn fetch_attachment(&self, tag: &str, file_id: &str) -> Result<File> {
+        self.find_one(
+            COL,
+            doc! {
+                "_id": file_id,
+                "tag": tag
+            },
+        )
+        .await
+    }
+
     async fn find_and_use_attachment(
         &self,
         id: &str,
         tag: &str,
         parent_type: &str,
         parent_id: &str,
+        uploader_id: Option<&str>,
     ) -> Result<File> {
         let key = format!("{parent_type}_id");
 
-        match self
-            .find_one::<File>(
-                COL,
-                doc! {
-                    "_id": id,
-                    "tag": tag,
-                    &key: {
-                        "$exists": true
-                    }
-                },
-            )
-            .await
-        {
+        // Files can only be used once, using one sets its parent ID.
+        let mut filter = doc! {
+            "_id": id,
+            "tag": tag,
+            &key: {
+                "$exists": false
+            }
+        };
+
+        if let Some(uploader_id) = uploader_id {
+            filter.insert("uploader_id", uploader_id);
+        }
+
+        match self.find_one::<File>(COL, filter).await {
             Ok(file) => {
                 self.col::<Document>(COL)
                     .update_one(
@@ -9,6 +9,7 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::ops::Deref;
 
 pub mod admin {
+    pub mod admin_action;
     pub mod migrations;
     pub mod stats;
 }
@@ -39,14 +40,17 @@ pub mod users {
 
 pub mod safety {
     pub mod report;
+    pub mod server_report;
     pub mod snapshot;
 }
 
 pub mod ratelimiter {
     pub mod ratelimit_event;
+    pub mod ratelimit_override;
 }
 
 pub mod webhooks {
+    pub mod outbound_webhook;
     pub mod webhook;
 }
 
@@ -3,12 +3,13 @@ use std::{
     time::{Duration, SystemTime},
 };
 
+use mongodb::options::FindOptions;
 use ulid::Ulid;
 
 use crate::{
     models::ratelimit_events::ratelimit::{RatelimitEvent, RatelimitEventType},
     ratelimiter::ratelimit::AbstractRatelimitEvent,
-    Error, Result,
+    Result,
 };
 
 static COL: &str = "ratelimit_events";
@@ -20,30 +21,28 @@ impl AbstractRatelimitEvent for MongoDb {
         self.insert_one(COL, event).await.map(|_| ())
     }
 
-    async fn has_ratelimited(
+    async fn fetch_ratelimit_events(
         &self,
         target_id: &str,
         event_type: RatelimitEventType,
         period: Duration,
-        count: usize,
-    ) -> Result<bool> {
-        self.col::<RatelimitEvent>(COL)
-            .count_documents(
-                doc! {
-                    "_id": {
-                        "$gte": Ulid::from_datetime(SystemTime::now() - period).to_string()
-                    },
-                    "target_id": target_id,
-                    "event_type": event_type.to_string()
+    ) -> Result<Vec<RatelimitEvent>> {
+        self.find_with_option(
+            COL,
+            doc! {
+                "_id": {
+                    "$gte": Ulid::from_datetime(SystemTime::now() - period).to_string()
                 },
-                None,
-            )
-            .await
-            .map(|c| c as usize >= count)
-            .map_err(|_| Error::DatabaseError {
-                operation: "count_documents",
-                with: COL,
-            })
+                "target_id": target_id,
+                "event_type": event_type.to_string()
+            },
+            FindOptions::builder()
+                .sort(doc! {
+                    "_id": 1_i32
+                })
+                .build(),
+        )
+        .await
     }
 }
 
@@ -0,0 +1,37 @@
+use mongodb::options::ReplaceOptions;
+
+use crate::{
+    models::ratelimit_events::ratelimit_override::RatelimitOverride,
+    ratelimiter::ratelimit_override::AbstractRatelimitOverride, Error, Result,
+};
+
+static COL: &str = "ratelimit_overrides";
+use super::super::MongoDb;
+
+#[async_trait]
+impl AbstractRatelimitOverride for MongoDb {
+    async fn fetch_ratelimit_override(&self, id: &str) -> Result<RatelimitOverride> {
+        self.find_one_by_id(COL, id).await
+    }
+
+    async fn set_ratelimit_override(&self, ratelimit_override: &RatelimitOverride) -> Result<()> {
+        self.col::<RatelimitOverride>(COL)
+            .replace_one(
+                doc! {
+                    "_id": &ratelimit_override.id
+                },
+                ratelimit_override,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "replace_one",
+                with: COL,
+            })
+    }
+
+    async fn delete_ratelimit_override(&self, id: &str) -> Result<()> {
+        self.delete_one_by_id(COL, id).await.map(|_| ())
+    }
+}
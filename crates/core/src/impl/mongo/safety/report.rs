@@ -1,6 +1,11 @@
+use bson::{to_bson, Document};
+use mongodb::options::FindOptions;
+
 use crate::{
-    models::{report::PartialReport, Report},
-    AbstractReport, Result,
+    models::report::{FieldsReport, PartialReport, ReportQuery},
+    models::Report,
+    r#impl::mongo::IntoDocumentPath,
+    AbstractReport, Error, Result,
 };
 
 use super::super::MongoDb;
@@ -13,17 +18,76 @@ impl AbstractReport for MongoDb {
         self.insert_one(COL, report).await.map(|_| ())
     }
 
-    async fn update_report(&self, id: &str, report: &PartialReport) -> Result<()> {
-        self.update_one_by_id(COL, id, report, vec![], None)
-            .await
-            .map(|_| ())
+    async fn update_report(
+        &self,
+        id: &str,
+        report: &PartialReport,
+        remove: Vec<FieldsReport>,
+    ) -> Result<()> {
+        self.update_one_by_id(
+            COL,
+            id,
+            report,
+            remove.iter().map(|x| x as &dyn IntoDocumentPath).collect(),
+            None,
+        )
+        .await
+        .map(|_| ())
     }
 
     async fn fetch_report(&self, report_id: &str) -> Result<Report> {
         self.find_one_by_id(COL, report_id).await
     }
 
-    async fn fetch_reports(&self) -> Result<Vec<Report>> {
-        self.find(COL, doc! {}).await
+    async fn fetch_reports(&self, query: ReportQuery) -> Result<Vec<Report>> {
+        let mut filter = Document::new();
+
+        if let Some(status) = query.status {
+            filter.insert(
+                "status",
+                to_bson(&status).map_err(|_| Error::DatabaseError {
+                    operation: "to_bson",
+                    with: "report_status",
+                })?,
+            );
+        }
+
+        if let Some(content_type) = query.content_type {
+            filter.insert(
+                "content.type",
+                to_bson(&content_type).map_err(|_| Error::DatabaseError {
+                    operation: "to_bson",
+                    with: "report_content_type",
+                })?,
+            );
+        }
+
+        if let Some(assigned_moderator_id) = query.assigned_moderator_id {
+            filter.insert("assigned_moderator_id", assigned_moderator_id);
+        }
+
+        if let Some(before) = query.before {
+            filter.insert("_id", doc! { "$lt": before });
+        }
+
+        self.find_with_option(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(query.limit)
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+}
+
+impl IntoDocumentPath for FieldsReport {
+    fn as_path(&self) -> Option<&'static str> {
+        Some(match self {
+            FieldsReport::AssignedModeratorId => "assigned_moderator_id",
+        })
     }
 }
@@ -0,0 +1,120 @@
+use bson::{to_bson, Document};
+use mongodb::options::FindOptions;
+
+use crate::{
+    models::server_report::{PartialServerReport, ServerReportStatus},
+    models::ServerReport,
+    AbstractServerReport, Error, Result,
+};
+
+use super::super::MongoDb;
+
+static COL: &str = "server_reports";
+
+#[async_trait]
+impl AbstractServerReport for MongoDb {
+    async fn insert_server_report(&self, report: &ServerReport) -> Result<()> {
+        self.insert_one(COL, report).await.map(|_| ())
+    }
+
+    async fn update_server_report(&self, id: &str, report: &PartialServerReport) -> Result<()> {
+        self.update_one_by_id(COL, id, report, vec![], None)
+            .await
+            .map(|_| ())
+    }
+
+    async fn fetch_server_report(&self, server_id: &str, report_id: &str) -> Result<ServerReport> {
+        self.find_one(
+            COL,
+            doc! {
+                "_id": report_id,
+                "server_id": server_id
+            },
+        )
+        .await
+    }
+
+    async fn fetch_server_reports(
+        &self,
+        server_id: &str,
+        status: Option<ServerReportStatus>,
+        before: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<ServerReport>> {
+        let mut filter = doc! {
+            "server_id": server_id
+        };
+
+        if let Some(status) = status {
+            filter.insert(
+                "status",
+                to_bson(&status).map_err(|_| Error::DatabaseError {
+                    operation: "to_bson",
+                    with: "server_report_status",
+                })?,
+            );
+        }
+
+        if let Some(before) = before {
+            filter.insert("_id", doc! { "$lt": before });
+        }
+
+        self.find_with_option(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(limit)
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+
+    async fn delete_server_reports(&self, server_id: &str) -> Result<()> {
+        let report_ids = self
+            .col::<Document>(COL)
+            .distinct(
+                "_id",
+                doc! {
+                    "server_id": server_id
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "distinct",
+                with: "server_reports",
+            })?;
+
+        self.col::<Document>("safety_snapshots")
+            .delete_many(
+                doc! {
+                    "report_id": {
+                        "$in": &report_ids
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_many",
+                with: "safety_snapshots",
+            })?;
+
+        self.col::<Document>(COL)
+            .delete_many(
+                doc! {
+                    "server_id": server_id
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_many",
+                with: "server_reports",
+            })
+    }
+}
@@ -20,4 +20,14 @@ impl AbstractSnapshot for MongoDb {
         )
         .await
     }
+
+    async fn fetch_snapshots(&self, report_id: &str) -> Result<Vec<Snapshot>> {
+        self.find(
+            COL,
+            doc! {
+                "report_id": report_id
+            },
+        )
+        .await
+    }
 }
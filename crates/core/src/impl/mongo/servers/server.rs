@@ -7,7 +7,7 @@ use crate::{
         Server,
     },
     r#impl::mongo::IntoDocumentPath,
-    AbstractServer, Error, Result,
+    AbstractOutboundWebhook, AbstractServer, AbstractServerReport, Error, Result,
 };
 
 use super::super::MongoDb;
@@ -48,6 +48,12 @@ impl MongoDb {
                 })?;
         }
 
+        for webhook in self.fetch_outbound_webhooks_for_server(&server.id).await? {
+            self.delete_outbound_webhook(&webhook.id).await?;
+        }
+
+        self.delete_server_reports(&server.id).await?;
+
         self.delete_many_attachments(doc! {
             "object_id":&server.id
         })
@@ -1,4 +1,6 @@
 use bson::{doc, Document};
+use futures::StreamExt;
+use mongodb::options::FindOptions;
 
 use super::super::MongoDb;
 use crate::{
@@ -94,6 +96,35 @@ impl AbstractServerMember for MongoDb {
         .await
     }
 
+    async fn fetch_member_ids(&self, server: &str) -> Result<Vec<String>> {
+        Ok(self
+            .col::<Document>(COL)
+            .find(
+                doc! {
+                    "_id.server": server
+                },
+                FindOptions::builder()
+                    .projection(doc! { "_id.user": 1 })
+                    .build(),
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "find",
+                with: "server_members",
+            })?
+            .filter_map(|s| async { s.ok() })
+            .collect::<Vec<Document>>()
+            .await
+            .into_iter()
+            .filter_map(|x| {
+                x.get_document("_id")
+                    .ok()
+                    .and_then(|id| id.get_str("user").ok())
+                    .map(|x| x.to_string())
+            })
+            .collect::<Vec<String>>())
+    }
+
     async fn fetch_member_count(&self, server: &str) -> Result<usize> {
         self.col::<Document>(COL)
             .count_documents(
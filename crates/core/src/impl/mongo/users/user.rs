@@ -1,5 +1,6 @@
-use bson::Document;
+use bson::{to_bson, Document};
 use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
 use mongodb::options::{Collation, CollationStrength, FindOneOptions, FindOptions};
 use once_cell::sync::Lazy;
 
@@ -108,6 +109,21 @@ impl AbstractUser for MongoDb {
         Ok(users)
     }
 
+    async fn fetch_users_with_expired_status(&self) -> Result<Vec<User>> {
+        self.find(
+            COL,
+            doc! {
+                "status.expires_at": {
+                    "$lte": to_bson(&Timestamp::now_utc()).map_err(|_| Error::DatabaseError {
+                        operation: "to_bson",
+                        with: "timestamp",
+                    })?
+                }
+            },
+        )
+        .await
+    }
+
     async fn is_username_taken(&self, username: &str) -> Result<bool> {
         // ! FIXME: move this up to generic
         match self.fetch_user_by_username(username).await {
@@ -313,6 +329,9 @@ impl IntoDocumentPath for FieldsUser {
             FieldsUser::Avatar => "avatar",
             FieldsUser::ProfileBackground => "profile.background",
             FieldsUser::ProfileContent => "profile.content",
+            FieldsUser::StatusActivity => "status.activity",
+            FieldsUser::StatusEmoji => "status.emoji",
+            FieldsUser::StatusExpiresAt => "status.expires_at",
             FieldsUser::StatusPresence => "status.presence",
             FieldsUser::StatusText => "status.text",
         })
@@ -0,0 +1,131 @@
+use std::time::{Duration, SystemTime};
+
+use bson::Document;
+use mongodb::options::FindOptions;
+use ulid::Ulid;
+
+use crate::{
+    models::outbound_webhook::{
+        FieldsOutboundWebhook, OutboundWebhook, OutboundWebhookDelivery, PartialOutboundWebhook,
+    },
+    r#impl::{mongo::IntoDocumentPath, MongoDb},
+    variables::delta::MONO_CHAT_OUTBOUND_WEBHOOK_RETENTION_DAYS,
+    AbstractOutboundWebhook, Error, Result,
+};
+
+static COL: &str = "outbound_webhooks";
+static DELIVERIES: &str = "outbound_webhook_deliveries";
+
+#[async_trait]
+impl AbstractOutboundWebhook for MongoDb {
+    async fn insert_outbound_webhook(&self, webhook: &OutboundWebhook) -> Result<()> {
+        self.insert_one(COL, webhook).await.map(|_| ())
+    }
+
+    async fn fetch_outbound_webhook(&self, webhook_id: &str) -> Result<OutboundWebhook> {
+        self.find_one_by_id(COL, webhook_id).await
+    }
+
+    async fn fetch_outbound_webhooks_for_server(
+        &self,
+        server_id: &str,
+    ) -> Result<Vec<OutboundWebhook>> {
+        self.find(
+            COL,
+            doc! {
+                "server_id": server_id
+            },
+        )
+        .await
+    }
+
+    async fn update_outbound_webhook(
+        &self,
+        webhook_id: &str,
+        partial: &PartialOutboundWebhook,
+        remove: &[FieldsOutboundWebhook],
+    ) -> Result<()> {
+        self.update_one_by_id(
+            COL,
+            webhook_id,
+            partial,
+            remove.iter().map(|x| x as &dyn IntoDocumentPath).collect(),
+            None,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn delete_outbound_webhook(&self, webhook_id: &str) -> Result<()> {
+        self.col::<Document>(DELIVERIES)
+            .delete_many(
+                doc! {
+                    "webhook_id": webhook_id
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_many",
+                with: DELIVERIES,
+            })?;
+
+        self.delete_one_by_id(COL, webhook_id).await.map(|_| ())
+    }
+
+    async fn insert_outbound_webhook_delivery(
+        &self,
+        delivery: &OutboundWebhookDelivery,
+    ) -> Result<()> {
+        self.insert_one(DELIVERIES, delivery).await?;
+
+        // Deliveries are only kept for a while, IDs are ordered by creation time.
+        let cutoff = SystemTime::now()
+            - Duration::from_secs(*MONO_CHAT_OUTBOUND_WEBHOOK_RETENTION_DAYS * 24 * 60 * 60);
+
+        self.col::<Document>(DELIVERIES)
+            .delete_many(
+                doc! {
+                    "webhook_id": &delivery.webhook_id,
+                    "_id": {
+                        "$lt": Ulid::from_datetime(cutoff).to_string()
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_many",
+                with: DELIVERIES,
+            })
+    }
+
+    async fn fetch_outbound_webhook_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: i64,
+    ) -> Result<Vec<OutboundWebhookDelivery>> {
+        self.find_with_option(
+            DELIVERIES,
+            doc! {
+                "webhook_id": webhook_id
+            },
+            FindOptions::builder()
+                .limit(limit)
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+}
+
+impl IntoDocumentPath for FieldsOutboundWebhook {
+    fn as_path(&self) -> Option<&'static str> {
+        Some(match self {
+            FieldsOutboundWebhook::ChannelId => "channel_id",
+        })
+    }
+}
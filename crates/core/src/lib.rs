@@ -25,9 +25,11 @@ pub mod events;
 pub mod r#impl;
 pub mod models;
 pub mod presence;
+pub mod storage;
 pub mod tasks;
 pub mod types;
 pub mod util;
+pub mod voice;
 
 #[cfg(feature = "rocket_impl")]
 pub mod web;
@@ -40,6 +42,7 @@ pub use database::*;
 pub use permissions::{get_relationship, perms};
 pub use presence::*;
 pub use traits::*;
+pub use voice::*;
 
 pub use util::{
     r#ref::Ref,
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Action taken through the platform administration API
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum AdminActionType {
+    LookupUser { query: String },
+    SetUserFlags { flags: u32 },
+    SetUserBadges { badges: u32 },
+    LogoutUser,
+    FetchUserServers,
+    EditReport,
+    SetRatelimitOverride { buckets: HashMap<String, u32> },
+}
+
+/// Entry in the admin action log
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct AdminAction {
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// User who performed the action
+    pub admin_id: String,
+
+    /// User or object the action was performed on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+
+    #[serde(flatten)]
+    pub action: AdminActionType,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
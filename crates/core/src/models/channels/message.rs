@@ -104,6 +104,117 @@ pub struct Interactions {
     pub restrict_reactions: bool,
 }
 
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub enum ButtonStyle {
+    Primary,
+    Secondary,
+    Success,
+    Danger,
+    Link,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct SelectOption {
+    pub label: String,
+    pub value: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub default: bool,
+}
+
+/// Interactive component which can be attached to a message
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum MessageComponent {
+    Button {
+        /// Identifier sent back to the bot when clicked, absent for link buttons
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_id: Option<String>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        label: Option<String>,
+
+        style: ButtonStyle,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<String>,
+
+        /// Destination of a link button
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+
+        #[serde(skip_serializing_if = "if_false", default)]
+        disabled: bool,
+    },
+    SelectMenu {
+        custom_id: String,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        placeholder: Option<String>,
+
+        options: Vec<SelectOption>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        min_values: Option<usize>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_values: Option<usize>,
+
+        #[serde(skip_serializing_if = "if_false", default)]
+        disabled: bool,
+    },
+}
+
+/// Row of components displayed beneath a message
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct ActionRow {
+    pub components: Vec<MessageComponent>,
+}
+
+/// Pending interaction created when a user uses a message component
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct MessageInteraction {
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    pub channel_id: String,
+    pub message_id: String,
+
+    /// User who used the component
+    pub user_id: String,
+
+    /// Bot which owns the message
+    pub bot_id: String,
+
+    pub custom_id: String,
+
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub values: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type")]
+pub enum InteractionResponse {
+    /// Edit the message the component is attached to
+    UpdateMessage {
+        content: Option<String>,
+        embeds: Option<Vec<SendableEmbed>>,
+        components: Option<Vec<ActionRow>>,
+    },
+    /// Reply with a message only visible to the user who interacted
+    EphemeralReply {
+        content: Option<String>,
+        embeds: Option<Vec<SendableEmbed>>,
+        components: Option<Vec<ActionRow>>,
+    },
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, OptionalStruct, Default, JsonSchema)]
 #[optional_derive(Serialize, Deserialize, Debug, Default, Clone, JsonSchema)]
 #[optional_name = "PartialMessage"]
@@ -147,6 +258,9 @@ pub struct Message {
     #[serde(skip_serializing_if = "Interactions::is_default", default)]
     pub interactions: Interactions,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ActionRow>>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub masquerade: Option<Masquerade>,
 }
@@ -225,6 +339,9 @@ pub struct DataMessageSend {
     #[validate]
     pub masquerade: Option<Masquerade>,
     pub interactions: Option<Interactions>,
+
+    /// Action rows of buttons and select menus, only usable by bots and webhooks
+    pub components: Option<Vec<ActionRow>>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
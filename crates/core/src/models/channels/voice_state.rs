@@ -0,0 +1,43 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Utility function to check if a boolean value is false
+pub fn if_false(t: &bool) -> bool {
+    !t
+}
+
+/// Voice state of a user connected to a call
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub struct VoiceState {
+    /// Id of the user
+    pub id: String,
+    /// Id of the channel the user is connected to
+    pub channel: String,
+
+    /// Whether the user has muted themselves
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub self_mute: bool,
+    /// Whether the user has deafened themselves
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub self_deaf: bool,
+    /// Whether the user was muted by a moderator
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub server_mute: bool,
+    /// Whether the user was deafened by a moderator
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub server_deaf: bool,
+}
+
+impl VoiceState {
+    /// Create a voice state for a user who just connected to a channel
+    pub fn new(id: String, channel: String) -> VoiceState {
+        VoiceState {
+            id,
+            channel,
+            self_mute: false,
+            self_deaf: false,
+            server_mute: false,
+            server_deaf: false,
+        }
+    }
+}
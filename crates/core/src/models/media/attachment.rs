@@ -11,6 +11,10 @@ pub enum Metadata {
         width: isize,
         height: isize,
     },
+    /// Video file
+    ///
+    /// Dimensions of files uploaded through the built-in storage are not
+    /// probed and are reported as zero, meaning unknown.
     Video {
         width: isize,
         height: isize,
@@ -28,6 +32,10 @@ pub struct File {
     pub content_type: String,
     pub size: isize,
 
+    /// ID of the user who uploaded this file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uploader_id: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
 
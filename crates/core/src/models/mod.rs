@@ -1,4 +1,5 @@
 pub mod admin {
+    pub mod admin_action;
     pub mod migrations;
     pub mod simple;
     pub mod stats;
@@ -9,10 +10,12 @@ pub mod channels {
     pub mod channel_invite;
     pub mod channel_unread;
     pub mod message;
+    pub mod voice_state;
 }
 
 pub mod ratelimit_events {
     pub mod ratelimit;
+    pub mod ratelimit_override;
 }
 
 pub mod media {
@@ -22,6 +25,7 @@ pub mod media {
 
 pub mod safety {
     pub mod report;
+    pub mod server_report;
     pub mod snapshot;
 }
 
@@ -38,6 +42,7 @@ pub mod servers {
 }
 
 pub mod webhooks {
+    pub mod outbound_webhook;
     pub mod webhook;
 }
 
@@ -57,11 +62,14 @@ pub use channel_unread::ChannelUnread;
 pub use emoji::Emoji;
 pub use message::Message;
 pub use migrations::MigrationInfo;
+pub use outbound_webhook::OutboundWebhook;
 pub use report::Report;
 pub use server::Server;
 pub use server_ban::ServerBan;
 pub use server_member::Member;
+pub use server_report::ServerReport;
 pub use simple::SimpleModel;
 pub use snapshot::Snapshot;
 pub use user::User;
 pub use user_settings::UserSettings;
+pub use voice_state::VoiceState;
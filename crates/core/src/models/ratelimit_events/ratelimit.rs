@@ -1,8 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RatelimitEventType {
     DiscriminatorChange,
+    UsernameChange,
+    ServerCreate,
+    FriendRequest,
+    GroupCreate,
+    InviteCreate,
+    ActivityChange,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -0,0 +1,15 @@
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Custom request limits for a trusted user or bot
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct RatelimitOverride {
+    /// User or bot ID
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Requests allowed per window, keyed by bucket name
+    pub buckets: HashMap<String, u32>,
+}
@@ -1,4 +1,6 @@
 use revolt_optional_struct::OptionalStruct;
+#[cfg(feature = "rocket_impl")]
+use rocket::FromFormField;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -48,6 +50,24 @@ pub enum ReportStatus {
     Resolved {},
 }
 
+/// Kind of content a report was filed against
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "rocket_impl", derive(FromFormField))]
+pub enum ReportedContentType {
+    Message,
+    Server,
+    User,
+}
+
+/// Report status without any associated data
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "rocket_impl", derive(FromFormField))]
+pub enum ReportStatusString {
+    Created,
+    Rejected,
+    Resolved,
+}
+
 #[derive(Serialize, Deserialize, JsonSchema, Debug, OptionalStruct, Clone)]
 #[optional_derive(Serialize, Deserialize, JsonSchema, Debug, Default, Clone)]
 #[optional_name = "PartialReport"]
@@ -64,4 +84,25 @@ pub struct Report {
 
     #[serde(default)]
     pub notes: String,
+
+    /// Moderator currently handling this report
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_moderator_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub enum FieldsReport {
+    AssignedModeratorId,
+}
+
+/// Filter used when paging through reports
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Default)]
+pub struct ReportQuery {
+    pub status: Option<ReportStatusString>,
+    pub content_type: Option<ReportedContentType>,
+    pub assigned_moderator_id: Option<String>,
+
+    /// Only include reports created before this ID
+    pub before: Option<String>,
+    pub limit: Option<i64>,
 }
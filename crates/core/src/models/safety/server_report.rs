@@ -0,0 +1,72 @@
+use iso8601_timestamp::Timestamp;
+use revolt_optional_struct::OptionalStruct;
+#[cfg(feature = "rocket_impl")]
+use rocket::FromFormField;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Content reported to a server's moderators
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum ServerReportedContent {
+    Message {
+        id: String,
+    },
+    Member {
+        id: String,
+        message_id: Option<String>,
+    },
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "rocket_impl", derive(FromFormField))]
+pub enum ServerReportStatus {
+    Open,
+    Actioned,
+    Dismissed,
+}
+
+/// Action a moderator took in response to a report
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum ServerReportAction {
+    Dismiss,
+    DeleteMessage,
+    Timeout { until: Timestamp },
+    Kick,
+    Ban { reason: Option<String> },
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, OptionalStruct, Clone)]
+#[optional_derive(Serialize, Deserialize, JsonSchema, Debug, Default, Clone)]
+#[optional_name = "PartialServerReport"]
+#[opt_skip_serializing_none]
+pub struct ServerReport {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub server_id: String,
+    pub author_id: String,
+    pub content: ServerReportedContent,
+
+    /// Member this report is about
+    pub reported_user_id: String,
+    pub additional_context: String,
+    pub status: ServerReportStatus,
+
+    /// Moderator who closed this report
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderator_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<ServerReportAction>,
+}
+
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataCreateServerReport {
+    pub content: ServerReportedContent,
+
+    #[validate(length(min = 0, max = 1000))]
+    #[serde(default)]
+    pub additional_context: String,
+}
@@ -1,8 +1,9 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::models::{Channel, Message, Server, User};
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
 #[serde(tag = "_type")]
 pub enum SnapshotContent {
     Message {
@@ -19,7 +20,7 @@ pub enum SnapshotContent {
     User(User),
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
 pub struct Snapshot {
     #[serde(rename = "_id")]
     pub id: String,
@@ -28,7 +29,7 @@ pub struct Snapshot {
     pub content: SnapshotContent,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, JsonSchema, Debug)]
 pub struct SnapshotWithContext {
     #[serde(flatten)]
     pub snapshot: Snapshot,
@@ -1,3 +1,4 @@
+use iso8601_timestamp::Timestamp;
 use num_enum::TryFromPrimitive;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -42,14 +43,54 @@ pub enum Presence {
     Invisible,
 }
 
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub enum ActivityType {
+    Playing,
+    Listening,
+    Watching,
+    Competing,
+}
+
+/// Structured activity shown alongside a user's status
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Validate)]
+pub struct UserActivity {
+    #[serde(rename = "type")]
+    pub activity_type: ActivityType,
+
+    /// Name of the game, song or show
+    #[validate(length(min = 1, max = 128))]
+    pub name: String,
+
+    #[validate(length(min = 1, max = 128))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+
+    /// When the activity was started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Timestamp>,
+}
+
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Validate, Default)]
 pub struct UserStatus {
     #[validate(length(min = 1, max = 128))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
+    /// Custom emoji ID or unicode emoji shown next to the status
+    #[validate(length(min = 1, max = 128))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub presence: Option<Presence>,
+
+    #[validate]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity: Option<UserActivity>,
+
+    /// When the text, emoji and activity should be cleared
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default)]
@@ -117,6 +158,12 @@ pub struct User {
 
     pub online: bool,
 
+    /// Presence shown to other users while online, resolved from the user's sessions
+    ///
+    /// Unlike `status.presence`, this is never stored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub online_presence: Option<Presence>,
+
     pub relationship: RelationshipStatus,
 }
 
@@ -138,7 +185,10 @@ pub struct BotInformation {
 pub enum FieldsUser {
     Avatar,
     StatusText,
+    StatusEmoji,
     StatusPresence,
+    StatusActivity,
+    StatusExpiresAt,
     ProfileContent,
     ProfileBackground,
 }
@@ -0,0 +1,120 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use revolt_optional_struct::OptionalStruct;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Deliveries may only be made over HTTP(S)
+pub static RE_WEBHOOK_URL: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^https?://").unwrap());
+
+pub fn if_false(t: &bool) -> bool {
+    !t
+}
+
+/// Kinds of events which can be delivered to an outbound webhook
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutboundEventType {
+    MemberJoin,
+    MemberLeave,
+    MessageCreate,
+    MessageUpdate,
+    MessageDelete,
+    /// Report filed with the server's moderators, with the reporter left out
+    ReportCreate,
+}
+
+/// HTTP subscription receiving events from a server
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, OptionalStruct)]
+#[optional_derive(Serialize, Deserialize, JsonSchema, Debug, Default, Clone)]
+#[optional_name = "PartialOutboundWebhook"]
+#[opt_skip_serializing_none]
+#[opt_some_priority]
+pub struct OutboundWebhook {
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    pub server_id: String,
+
+    /// Only deliver events from this channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+
+    pub creator_id: String,
+
+    pub url: String,
+
+    pub events: Vec<OutboundEventType>,
+
+    /// Key used to sign deliveries, only returned on creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+
+    pub enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub enum FieldsOutboundWebhook {
+    ChannelId,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    Success,
+    Failed,
+}
+
+/// Record of an attempt to deliver an event to an outbound webhook
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct OutboundWebhookDelivery {
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    pub webhook_id: String,
+
+    pub event_type: OutboundEventType,
+
+    pub status: DeliveryStatus,
+
+    /// Number of requests made before giving up or succeeding
+    pub attempts: u32,
+
+    /// HTTP status of the last response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_status: Option<u16>,
+
+    /// Reason the last request failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub test: bool,
+}
+
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataCreateOutboundWebhook {
+    #[validate(url, length(min = 1, max = 512), regex = "RE_WEBHOOK_URL")]
+    pub url: String,
+
+    #[validate(length(min = 1))]
+    pub events: Vec<OutboundEventType>,
+
+    /// Channel to scope the subscription to
+    pub channel_id: Option<String>,
+}
+
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataEditOutboundWebhook {
+    #[validate(url, length(min = 1, max = 512), regex = "RE_WEBHOOK_URL")]
+    pub url: Option<String>,
+
+    #[validate(length(min = 1))]
+    pub events: Option<Vec<OutboundEventType>>,
+
+    pub channel_id: Option<String>,
+
+    pub enabled: Option<bool>,
+
+    #[serde(default)]
+    pub remove: Vec<FieldsOutboundWebhook>,
+}
@@ -19,6 +19,7 @@ pub enum ChannelPermission {
     ManagePermissions = 1 << 2,
     ManageRole = 1 << 3,
     ManageCustomisation = 1 << 4,
+    ManageReports = 1 << 5,
     KickMembers = 1 << 6,
     BanMembers = 1 << 7,
     TimeoutMembers = 1 << 8,
@@ -87,6 +88,7 @@ bitfield! {
     pub can_manage_permissions, _: 61;
     pub can_manage_roles, _: 60;
     pub can_manage_customisation, _: 59;
+    pub can_manage_reports, _: 58;
 
     // * Member permissions
     pub can_kick_members, _: 57;
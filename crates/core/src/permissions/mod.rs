@@ -165,6 +165,24 @@ impl<'a> PermissionCalculator<'a> {
             .get()
             .map(|member| member.get_ranking(self.server.get().unwrap()))
     }
+
+    /// Check whether we rank above another member of the known server, otherwise throw an error
+    ///
+    /// Always passes outside of servers, for the server owner and when acting on ourselves.
+    pub async fn throw_elevated_over(&self, db: &Database, user_id: &str) -> Result<()> {
+        if let Some(server) = self.server.get() {
+            if server.owner == self.perspective.id || user_id == self.perspective.id {
+                return Ok(());
+            }
+
+            let member = db.fetch_member(&server.id, user_id).await?;
+            if member.get_ranking(server) <= self.get_member_rank().unwrap_or(i64::MIN) {
+                return Err(Error::NotElevated);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 pub fn perms(perspective: &'_ User) -> PermissionCalculator<'_> {
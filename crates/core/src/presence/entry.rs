@@ -3,6 +3,8 @@ use std::env;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
+use crate::models::user::Presence;
+
 pub static REGION_ID: Lazy<u16> = Lazy::new(|| {
     env::var("REGION_ID")
         .unwrap_or_else(|_| "0".to_string())
@@ -23,9 +25,63 @@ pub struct PresenceEntry {
     pub session_id: u8,
 
     /// Known flags about session
+    ///
+    /// The lowest two bits hold the session's presence
     pub flags: u8,
 }
 
+/// Presence reported by an individual session
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionPresence {
+    /// User is interacting with this client
+    Active = 0,
+    /// Client has not seen any input for a while
+    Idle = 1,
+    /// User has asked not to be distracted on this client
+    Focus = 2,
+}
+
+impl SessionPresence {
+    const MASK: u8 = 0b11;
+
+    /// Read session presence from entry flags
+    pub fn from_flags(flags: u8) -> Self {
+        match flags & Self::MASK {
+            1 => SessionPresence::Idle,
+            2 => SessionPresence::Focus,
+            _ => SessionPresence::Active,
+        }
+    }
+
+    /// Write session presence into entry flags, leaving other flags intact
+    pub fn into_flags(self, flags: u8) -> u8 {
+        (flags & !Self::MASK) | self as u8
+    }
+
+    /// Priority used when combining sessions, higher wins
+    fn priority(self) -> u8 {
+        match self {
+            SessionPresence::Active => 2,
+            SessionPresence::Focus => 1,
+            SessionPresence::Idle => 0,
+        }
+    }
+
+    /// Resolve the presence shown to other users
+    ///
+    /// A presence explicitly chosen by the user always takes priority.
+    pub fn resolve(self, chosen: Option<&Presence>) -> Presence {
+        match chosen {
+            None | Some(Presence::Online) => match self {
+                SessionPresence::Active => Presence::Online,
+                SessionPresence::Idle => Presence::Idle,
+                SessionPresence::Focus => Presence::Focus,
+            },
+            Some(presence) => presence.clone(),
+        }
+    }
+}
+
 impl PresenceEntry {
     /// Create a new presence entry from a given session ID and known flags
     pub fn from(session_id: u8, flags: u8) -> Self {
@@ -35,10 +91,18 @@ impl PresenceEntry {
             flags,
         }
     }
+
+    /// Presence reported by this session
+    pub fn presence(&self) -> SessionPresence {
+        SessionPresence::from_flags(self.flags)
+    }
 }
 pub trait PresenceOp {
     /// Find next available session ID
     fn find_next_id(&self) -> u8;
+
+    /// Combine all sessions into the user's effective presence
+    fn effective_presence(&self) -> Option<SessionPresence>;
 }
 
 impl PresenceOp for Vec<PresenceEntry> {
@@ -61,4 +125,10 @@ impl PresenceOp for Vec<PresenceEntry> {
 
         255
     }
+
+    fn effective_presence(&self) -> Option<SessionPresence> {
+        self.iter()
+            .map(PresenceEntry::presence)
+            .max_by_key(|presence| presence.priority())
+    }
 }
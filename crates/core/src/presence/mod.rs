@@ -1,10 +1,11 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use redis_kiss::{get_connection, AsyncCommands};
 
 mod entry;
 mod operations;
 
+pub use entry::SessionPresence;
 use entry::{PresenceEntry, PresenceOp};
 use operations::{
     __add_to_set_sessions, __delete_key_presence_entry, __get_key_presence_entry,
@@ -15,7 +16,14 @@ use crate::presence::operations::__delete_set_sessions;
 
 use self::entry::REGION_KEY;
 
-pub async fn presence_create_session(user_id: &str, flags: u8) -> (bool, u8) {
+/// Create a new presence session
+///
+/// Returns whether this was the first session, the new session ID and
+/// the user's effective presence if it changed as a result.
+pub async fn presence_create_session(
+    user_id: &str,
+    flags: u8,
+) -> (bool, u8, Option<SessionPresence>) {
     info!("Creating a presence session for {user_id} with flags {flags}");
 
     let mut conn = get_connection().await.unwrap();
@@ -24,20 +32,56 @@ pub async fn presence_create_session(user_id: &str, flags: u8) -> (bool, u8) {
         .unwrap_or_default();
 
     let was_empty = entry.is_empty();
+    let previous = entry.effective_presence();
     info!("User ID {} just came online.", &user_id);
 
     // Generate session ID and push new entry.
     let session_id = entry.find_next_id();
     entry.push(PresenceEntry::from(session_id, flags));
+    let effective = entry.effective_presence();
     __set_key_presence_entry(&mut conn, user_id, entry).await;
 
     // Add to region set in case of failure.
     __add_to_set_sessions(&mut conn, &REGION_KEY, user_id, session_id).await;
-    (was_empty, session_id)
+    (
+        was_empty,
+        session_id,
+        effective.filter(|_| effective != previous),
+    )
+}
+
+/// Update the presence reported by an existing session
+///
+/// Returns the user's effective presence if it changed as a result.
+pub async fn presence_set_session(
+    user_id: &str,
+    session_id: u8,
+    presence: SessionPresence,
+) -> Option<SessionPresence> {
+    let mut conn = get_connection().await.unwrap();
+    let mut entry: Vec<PresenceEntry> = __get_key_presence_entry(&mut conn, user_id).await?;
+
+    let previous = entry.effective_presence();
+    let session = entry.iter_mut().find(|x| x.session_id == session_id)?;
+    if session.presence() == presence {
+        return None;
+    }
+
+    session.flags = presence.into_flags(session.flags);
+    let effective = entry.effective_presence();
+    __set_key_presence_entry(&mut conn, user_id, entry).await;
+
+    effective.filter(|_| effective != previous)
 }
 
 /// Delete existing presence session
-pub async fn presence_delete_session(user_id: &str, session_id: u8) -> bool {
+///
+/// Returns whether this was the last session and the user's
+/// effective presence if it changed but they remain online.
+pub async fn presence_delete_session(
+    user_id: &str,
+    session_id: u8,
+) -> (bool, Option<SessionPresence>) {
     presence_delete_session_internal(user_id, session_id, false).await
 }
 
@@ -46,16 +90,18 @@ async fn presence_delete_session_internal(
     user_id: &str,
     session_id: u8,
     skip_region: bool,
-) -> bool {
+) -> (bool, Option<SessionPresence>) {
     info!("Deleting presence session for {user_id} with id {session_id}");
 
     // Return whether this was the last session.
     let mut is_empty = false;
+    let mut changed = None;
 
     // Only continue if we can actually find one.
     let mut conn = get_connection().await.unwrap();
     let entry: Option<Vec<PresenceEntry>> = __get_key_presence_entry(&mut conn, user_id).await;
     if let Some(entry) = entry {
+        let previous = entry.effective_presence();
         let entries = entry
             .into_iter()
             .filter(|x| x.session_id != session_id)
@@ -66,6 +112,11 @@ async fn presence_delete_session_internal(
             __delete_key_presence_entry(&mut conn, user_id).await;
             is_empty = true;
         } else {
+            let effective = entries.effective_presence();
+            if effective != previous {
+                changed = effective;
+            }
+
             __set_key_presence_entry(&mut conn, user_id, entries).await;
         }
 
@@ -79,7 +130,7 @@ async fn presence_delete_session_internal(
         info!("User ID {} just went offline.", &user_id);
     }
 
-    is_empty
+    (is_empty, changed)
 }
 
 /// Check whether a given user ID is online
@@ -128,6 +179,34 @@ pub async fn presence_filter_online(user_ids: &'_ [String]) -> HashSet<String> {
     set
 }
 
+/// Fetch the effective presence of a set of users, offline users are omitted
+pub async fn presence_filter_effective(user_ids: &'_ [String]) -> HashMap<String, SessionPresence> {
+    let mut map = HashMap::new();
+    if user_ids.is_empty() {
+        return map;
+    }
+
+    if let Ok(mut conn) = get_connection().await {
+        // Same as above, Redis does not like MGET with a single key.
+        let data: Vec<Option<Vec<u8>>> = if user_ids.len() == 1 {
+            vec![conn.get(&user_ids[0]).await.unwrap_or_default()]
+        } else {
+            conn.get(user_ids).await.unwrap_or_default()
+        };
+
+        for (user_id, entry) in user_ids.iter().zip(data) {
+            if let Some(presence) = entry
+                .and_then(|entry| bincode::deserialize::<Vec<PresenceEntry>>(&entry[..]).ok())
+                .and_then(|entry| entry.effective_presence())
+            {
+                map.insert(user_id.to_string(), presence);
+            }
+        }
+    }
+
+    map
+}
+
 /// Reset any stale presence data
 pub async fn presence_clear_region(region_id: Option<&str>) {
     let region_id = region_id.unwrap_or(&*REGION_KEY);
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use async_std::fs;
+
+use crate::{variables::delta::MONO_CHAT_STORAGE_PATH, Error, Result};
+
+use super::FileStorage;
+
+/// Store files in a directory on the local filesystem, one subdirectory per tag
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: PathBuf) -> LocalStorage {
+        LocalStorage { root }
+    }
+
+    /// Create a storage rooted at `MONO_CHAT_STORAGE_PATH`
+    pub fn from_env() -> LocalStorage {
+        LocalStorage::new(PathBuf::from(&*MONO_CHAT_STORAGE_PATH))
+    }
+
+    fn path(&self, tag: &str, id: &str) -> PathBuf {
+        self.root.join(tag).join(id)
+    }
+}
+
+#[async_trait]
+impl FileStorage for LocalStorage {
+    async fn put(&self, tag: &str, id: &str, data: Vec<u8>, _content_type: &str) -> Result<()> {
+        fs::create_dir_all(self.root.join(tag))
+            .await
+            .map_err(|_| Error::InternalError)?;
+
+        fs::write(self.path(tag, id), data)
+            .await
+            .map_err(|_| Error::InternalError)
+    }
+
+    async fn get(&self, tag: &str, id: &str) -> Result<Vec<u8>> {
+        fs::read(self.path(tag, id))
+            .await
+            .map_err(|_| Error::NotFound)
+    }
+}
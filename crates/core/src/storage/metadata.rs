@@ -0,0 +1,46 @@
+use crate::models::attachment::Metadata;
+
+/// Determine the content type of a file from its leading bytes
+///
+/// Falls back to `text/plain` for valid UTF-8 and `application/octet-stream` otherwise.
+pub fn sniff_content_type(data: &[u8]) -> &'static str {
+    match data {
+        [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n', ..] => "image/png",
+        [0xFF, 0xD8, 0xFF, ..] => "image/jpeg",
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => "image/gif",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => "image/webp",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', ..] => "audio/wav",
+        [b'O', b'g', b'g', b'S', ..] => "audio/ogg",
+        [b'f', b'L', b'a', b'C', ..] => "audio/flac",
+        [b'I', b'D', b'3', ..] | [0xFF, 0xFB | 0xF3 | 0xF2, ..] => "audio/mpeg",
+        [_, _, _, _, b'f', b't', b'y', b'p', b'M', b'4', b'A', b' ', ..] => "audio/mp4",
+        [_, _, _, _, b'f', b't', b'y', b'p', b'q', b't', b' ', b' ', ..] => "video/quicktime",
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => "video/mp4",
+        [0x1A, 0x45, 0xDF, 0xA3, ..] => "video/webm",
+        [b'%', b'P', b'D', b'F', b'-', ..] => "application/pdf",
+        _ if !data.contains(&0) && std::str::from_utf8(data).is_ok() => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Extract metadata for a file of the given content type
+///
+/// Video dimensions are not probed and are left as zero, meaning unknown.
+pub fn extract_metadata(content_type: &str, data: &[u8]) -> Metadata {
+    match content_type.split('/').next() {
+        Some("image") => match imagesize::blob_size(data) {
+            Ok(size) => Metadata::Image {
+                width: size.width as isize,
+                height: size.height as isize,
+            },
+            Err(_) => Metadata::File,
+        },
+        Some("video") => Metadata::Video {
+            width: 0,
+            height: 0,
+        },
+        Some("audio") => Metadata::Audio,
+        Some("text") => Metadata::Text,
+        _ => Metadata::File,
+    }
+}
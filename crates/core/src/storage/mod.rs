@@ -0,0 +1,69 @@
+use once_cell::sync::Lazy;
+
+use crate::{variables::delta::MONO_CHAT_STORAGE, Result};
+
+mod local;
+mod metadata;
+mod s3;
+
+pub use self::local::LocalStorage;
+pub use self::metadata::{extract_metadata, sniff_content_type};
+pub use self::s3::S3Storage;
+
+/// Backing store for uploaded files
+///
+/// Files are addressed by their tag and ID, and never change once written.
+#[async_trait]
+pub trait FileStorage: Sync + Send {
+    /// Write a file
+    async fn put(&self, tag: &str, id: &str, data: Vec<u8>, content_type: &str) -> Result<()>;
+
+    /// Read a file back
+    async fn get(&self, tag: &str, id: &str) -> Result<Vec<u8>>;
+}
+
+/// Upload limits for a file tag
+#[derive(Debug, Clone, Copy)]
+pub struct TagLimits {
+    /// Maximum file size in bytes
+    pub max_size: usize,
+    /// Whether only images may be uploaded
+    pub images_only: bool,
+}
+
+/// Largest file which may be uploaded to any tag, in bytes
+pub const MAX_FILE_SIZE: usize = 20_000_000;
+
+/// Find the upload limits for a tag, None if files cannot be uploaded to it
+pub fn tag_limits(tag: &str) -> Option<TagLimits> {
+    let (max_size, images_only) = match tag {
+        "attachments" => (MAX_FILE_SIZE, false),
+        "avatars" => (4_000_000, true),
+        "backgrounds" => (6_000_000, true),
+        "icons" => (2_500_000, true),
+        "banners" => (6_000_000, true),
+        "emojis" => (500_000, true),
+        _ => return None,
+    };
+
+    Some(TagLimits {
+        max_size,
+        images_only,
+    })
+}
+
+static FILE_STORAGE: Lazy<Box<dyn FileStorage>> = Lazy::new(create_file_storage);
+
+/// Create the file storage selected by `MONO_CHAT_STORAGE`
+fn create_file_storage() -> Box<dyn FileStorage> {
+    match MONO_CHAT_STORAGE.as_str() {
+        "local" => Box::new(LocalStorage::from_env()),
+        "s3" => Box::new(S3Storage::from_env()),
+        other => panic!("Unknown file storage `{other}`, expected `local` or `s3`."),
+    }
+}
+
+/// File storage configured for this process
+pub fn file_storage() -> &'static dyn FileStorage {
+    FILE_STORAGE.as_ref()
+}
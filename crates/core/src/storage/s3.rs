@@ -0,0 +1,191 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use reqwest::{Method, StatusCode, Url};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    variables::delta::{
+        MONO_CHAT_S3_ACCESS_KEY_ID, MONO_CHAT_S3_BUCKET, MONO_CHAT_S3_ENDPOINT,
+        MONO_CHAT_S3_REGION, MONO_CHAT_S3_SECRET_ACCESS_KEY,
+    },
+    Error, Result,
+};
+
+use super::FileStorage;
+
+/// Store files in an S3 compatible bucket, keyed by `{tag}/{id}`
+///
+/// Requests use path-style addressing, which every S3 compatible service supports.
+pub struct S3Storage {
+    endpoint: String,
+    region: String,
+    bucket: String,
+    access_key_id: String,
+    secret_access_key: String,
+    client: reqwest::Client,
+}
+
+impl S3Storage {
+    pub fn new(
+        endpoint: String,
+        region: String,
+        bucket: String,
+        access_key_id: String,
+        secret_access_key: String,
+    ) -> S3Storage {
+        S3Storage {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            region,
+            bucket,
+            access_key_id,
+            secret_access_key,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Create a storage from the `MONO_CHAT_S3_*` environment variables
+    pub fn from_env() -> S3Storage {
+        S3Storage::new(
+            MONO_CHAT_S3_ENDPOINT.to_string(),
+            MONO_CHAT_S3_REGION.to_string(),
+            MONO_CHAT_S3_BUCKET.to_string(),
+            MONO_CHAT_S3_ACCESS_KEY_ID.to_string(),
+            MONO_CHAT_S3_SECRET_ACCESS_KEY.to_string(),
+        )
+    }
+
+    /// Build a request for an object, signed with AWS Signature Version 4
+    fn request(
+        &self,
+        method: Method,
+        tag: &str,
+        id: &str,
+        payload: &[u8],
+    ) -> reqwest::RequestBuilder {
+        let path = format!("/{}/{tag}/{id}", self.bucket);
+        let url = Url::parse(&format!("{}{path}", self.endpoint)).expect("Valid S3 endpoint");
+        let host = match url.port() {
+            Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+            None => url.host_str().unwrap_or_default().to_string(),
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs();
+
+        let (amz_date, date) = format_amz_date(now);
+        let payload_hash = hex::encode(Sha256::digest(payload));
+        let scope = format!("{date}/{}/s3/aws4_request", self.region);
+
+        let canonical_request = format!(
+            "{method}\n{}\n\nhost:{host}\nx-amz-content-sha256:{payload_hash}\nx-amz-date:{amz_date}\n\nhost;x-amz-content-sha256;x-amz-date\n{payload_hash}",
+            url.path()
+        );
+
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let signing_key = [self.region.as_bytes(), &b"s3"[..], &b"aws4_request"[..]]
+            .into_iter()
+            .fold(
+                hmac_sha256(
+                    format!("AWS4{}", self.secret_access_key).as_bytes(),
+                    date.as_bytes(),
+                ),
+                |key, part| hmac_sha256(&key, part),
+            );
+
+        let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+        self.client
+            .request(method, url)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!(
+                    "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders=host;x-amz-content-sha256;x-amz-date, Signature={signature}",
+                    self.access_key_id
+                ),
+            )
+    }
+}
+
+#[async_trait]
+impl FileStorage for S3Storage {
+    async fn put(&self, tag: &str, id: &str, data: Vec<u8>, content_type: &str) -> Result<()> {
+        let response = self
+            .request(Method::PUT, tag, id, &data)
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(data)
+            .send()
+            .await
+            .map_err(|_| Error::InternalError)?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            warn!("Failed to upload {tag}/{id} to S3: {}", response.status());
+            Err(Error::InternalError)
+        }
+    }
+
+    async fn get(&self, tag: &str, id: &str) -> Result<Vec<u8>> {
+        let response = self
+            .request(Method::GET, tag, id, &[])
+            .send()
+            .await
+            .map_err(|_| Error::InternalError)?;
+
+        match response.status() {
+            StatusCode::OK => response
+                .bytes()
+                .await
+                .map(|bytes| bytes.to_vec())
+                .map_err(|_| Error::InternalError),
+            StatusCode::NOT_FOUND => Err(Error::NotFound),
+            status => {
+                warn!("Failed to fetch {tag}/{id} from S3: {status}");
+                Err(Error::InternalError)
+            }
+        }
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Format a Unix timestamp as an `x-amz-date` timestamp and a credential scope date
+fn format_amz_date(timestamp: u64) -> (String, String) {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    // Convert days since the epoch to a civil date (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    let date = format!("{year:04}{month:02}{day:02}");
+    (
+        format!(
+            "{date}T{:02}{:02}{:02}Z",
+            seconds / 3_600,
+            seconds % 3_600 / 60,
+            seconds % 60
+        ),
+        date,
+    )
+}
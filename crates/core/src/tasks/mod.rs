@@ -6,7 +6,9 @@ const WORKER_COUNT: usize = 5;
 use crate::Database;
 pub mod ack;
 pub mod last_message_id;
+pub mod outbound_webhooks;
 pub mod process_embeds;
+pub mod status_expiry;
 pub mod web_push;
 
 pub struct DelayedTask<T> {
@@ -20,10 +22,16 @@ pub async fn start_workers(db: Database, authifier_db: authifier::Database) {
         task::spawn(ack::worker(db.clone()));
         task::spawn(last_message_id::worker(db.clone()));
         task::spawn(process_embeds::worker(db.clone()));
+        task::spawn(outbound_webhooks::worker(db.clone()));
         task::spawn(web_push::worker(authifier_db.clone()));
     }
 }
 
+/// Start workers which sweep the whole database, these must only be started once
+pub fn start_sweepers(db: Database) {
+    task::spawn(status_expiry::worker(db));
+}
+
 static EXPIRE_CONSTANT: u64 = 30;
 
 static SAVE_CONSTANT: u64 = 5;
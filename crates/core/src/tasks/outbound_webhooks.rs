@@ -0,0 +1,272 @@
+use crate::database::Database;
+use crate::events::client::EventV1;
+use crate::models::outbound_webhook::{
+    DeliveryStatus, OutboundEventType, OutboundWebhook, OutboundWebhookDelivery,
+};
+use crate::models::Channel;
+use crate::permissions::defn::ChannelPermission;
+use crate::perms;
+use crate::util::guard::resolve_public_url;
+use crate::variables::delta::MONO_CHAT_OUTBOUND_WEBHOOK_ATTEMPTS;
+use crate::{Error, Result};
+use async_std::task::{sleep, spawn};
+use deadqueue::limited::Queue;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use ulid::Ulid;
+
+use isahc::config::{RedirectPolicy, ResolveMap};
+use isahc::prelude::*;
+use isahc::HttpClient;
+
+/// Task information
+#[derive(Debug)]
+struct OutboundTask {
+    server_id: Option<String>,
+    channel_id: Option<String>,
+    event_type: OutboundEventType,
+    event: EventV1,
+}
+
+/// Body posted to the subscribed URL
+#[derive(Serialize)]
+struct OutboundPayload<'a> {
+    id: &'a str,
+    webhook_id: &'a str,
+    server_id: &'a str,
+    #[serde(rename = "type")]
+    event_type: OutboundEventType,
+    timestamp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<&'a EventV1>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    test: bool,
+}
+
+static Q: Lazy<Queue<OutboundTask>> = Lazy::new(|| Queue::new(10_000));
+
+/// Queue an event for delivery to any matching outbound webhooks
+///
+/// If the server is not known, it is resolved from the channel.
+pub async fn queue(server_id: Option<String>, channel_id: Option<String>, event: EventV1) {
+    let event_type = match OutboundEventType::from_event(&event) {
+        Some(event_type) => event_type,
+        None => return,
+    };
+
+    Q.try_push(OutboundTask {
+        server_id,
+        channel_id,
+        event_type,
+        event,
+    })
+    .ok();
+
+    info!("Queue is using {} slots from {}.", Q.len(), Q.capacity());
+}
+
+pub async fn worker(db: Database) {
+    loop {
+        let task = Q.pop().await;
+        let db = db.clone();
+
+        spawn(async move {
+            let channel = match &task.channel_id {
+                Some(channel_id) => match db.fetch_channel(channel_id).await {
+                    Ok(channel) => Some(channel),
+                    Err(_) => return,
+                },
+                None => None,
+            };
+
+            let server_id = match (task.server_id, &channel) {
+                (Some(server_id), _) => server_id,
+                (
+                    None,
+                    Some(
+                        Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. },
+                    ),
+                ) => server.clone(),
+                _ => return,
+            };
+
+            let webhooks = match db.fetch_outbound_webhooks_for_server(&server_id).await {
+                Ok(webhooks) => webhooks,
+                Err(err) => {
+                    error!("Failed to fetch outbound webhooks: {:?}", err);
+                    return;
+                }
+            };
+
+            for webhook in webhooks {
+                if webhook.wants(task.event_type, task.channel_id.as_deref())
+                    && creator_can_view(&db, &webhook, task.event_type, channel.as_ref()).await
+                {
+                    if let Err(err) = deliver(
+                        &db,
+                        &webhook,
+                        task.event_type,
+                        Some(&task.event),
+                        *MONO_CHAT_OUTBOUND_WEBHOOK_ATTEMPTS,
+                    )
+                    .await
+                    {
+                        error!("Failed to deliver outbound webhook: {:?}", err);
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Whether the creator of a webhook can still see the channel an event came from
+///
+/// Reports additionally require the creator to be able to manage them, other
+/// events which are not tied to a channel are always visible.
+async fn creator_can_view(
+    db: &Database,
+    webhook: &OutboundWebhook,
+    event_type: OutboundEventType,
+    channel: Option<&Channel>,
+) -> bool {
+    if channel.is_none() && event_type != OutboundEventType::ReportCreate {
+        return true;
+    }
+
+    let creator = match db.fetch_user(&webhook.creator_id).await {
+        Ok(creator) => creator,
+        Err(_) => return false,
+    };
+
+    if event_type == OutboundEventType::ReportCreate {
+        let server = match db.fetch_server(&webhook.server_id).await {
+            Ok(server) => server,
+            Err(_) => return false,
+        };
+
+        return perms(&creator)
+            .server(&server)
+            .has_permission(db, ChannelPermission::ManageReports)
+            .await
+            .unwrap_or_default();
+    }
+
+    let channel = match channel {
+        Some(channel) => channel,
+        None => return true,
+    };
+
+    perms(&creator)
+        .channel(channel)
+        .has_permission(db, ChannelPermission::ViewChannel)
+        .await
+        .unwrap_or_default()
+}
+
+/// Deliver an event to a webhook, retrying with exponential backoff, and log the outcome
+///
+/// Passing no event sends a test delivery.
+pub async fn deliver(
+    db: &Database,
+    webhook: &OutboundWebhook,
+    event_type: OutboundEventType,
+    event: Option<&EventV1>,
+    max_attempts: u32,
+) -> Result<OutboundWebhookDelivery> {
+    let mut delivery = OutboundWebhookDelivery {
+        id: Ulid::new().to_string(),
+        webhook_id: webhook.id.to_string(),
+        event_type,
+        status: DeliveryStatus::Failed,
+        attempts: 0,
+        response_status: None,
+        error: None,
+        test: event.is_none(),
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| Error::InternalError)?
+        .as_secs();
+
+    let body = serde_json::to_string(&OutboundPayload {
+        id: &delivery.id,
+        webhook_id: &webhook.id,
+        server_id: &webhook.server_id,
+        event_type,
+        timestamp,
+        data: event,
+        test: event.is_none(),
+    })
+    .map_err(|_| Error::InternalError)?;
+
+    let signature = OutboundWebhook::sign(
+        webhook.secret.as_deref().unwrap_or_default(),
+        timestamp,
+        &body,
+    );
+
+    // Pin the connection to the address which was checked, and never follow
+    // redirects or go through a proxy, so internal services cannot be reached.
+    let target = match resolve_public_url(&webhook.url).await {
+        Ok(target) => target,
+        Err(_) => {
+            delivery.error = Some("Refused to deliver to a non-public address".to_string());
+            db.insert_outbound_webhook_delivery(&delivery).await?;
+            return Ok(delivery);
+        }
+    };
+
+    let client = HttpClient::builder()
+        .dns_resolve(ResolveMap::new().add(&target.host, target.port, target.addrs[0]))
+        .redirect_policy(RedirectPolicy::None)
+        .proxy(None)
+        .build()
+        .map_err(|_| Error::InternalError)?;
+
+    while delivery.attempts < max_attempts.max(1) {
+        if delivery.attempts > 0 {
+            sleep(Duration::from_secs(2_u64.pow(delivery.attempts))).await;
+        }
+
+        delivery.attempts += 1;
+
+        let request = Request::post(&webhook.url)
+            .header("Content-Type", "application/json")
+            .header("User-Agent", "MonoChat-Webhooks")
+            .header("X-Delivery-Id", &delivery.id)
+            .header("X-Timestamp", timestamp.to_string())
+            .header("X-Signature-256", &signature)
+            .timeout(Duration::from_secs(10))
+            .body(body.clone())
+            .map_err(|_| Error::InternalError)?;
+
+        match client.send_async(request).await {
+            Ok(response) => {
+                let status = response.status();
+                delivery.response_status = Some(status.as_u16());
+
+                if status.is_success() {
+                    delivery.status = DeliveryStatus::Success;
+                    delivery.error = None;
+                    break;
+                }
+
+                delivery.error = Some(format!("Received status {status}"));
+
+                // Client errors will not resolve themselves by retrying
+                if status.is_client_error() && status.as_u16() != 429 {
+                    break;
+                }
+            }
+            Err(err) => {
+                delivery.response_status = None;
+                delivery.error = Some(err.to_string());
+            }
+        }
+    }
+
+    db.insert_outbound_webhook_delivery(&delivery).await?;
+    Ok(delivery)
+}
@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use async_std::task::sleep;
+
+use crate::Database;
+
+/// How often to look for statuses which have expired
+const INTERVAL: Duration = Duration::from_secs(30);
+
+pub async fn worker(db: Database) {
+    loop {
+        match db.fetch_users_with_expired_status().await {
+            Ok(users) => {
+                for mut user in users {
+                    if let Err(err) = user.clear_expired_status(&db).await {
+                        error!("Failed to clear expired status for {}: {err:?}", user.id);
+                    }
+                }
+            }
+            Err(err) => error!("Failed to fetch expired statuses: {err:?}"),
+        }
+
+        sleep(INTERVAL).await;
+    }
+}
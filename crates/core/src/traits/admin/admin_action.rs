@@ -0,0 +1,12 @@
+use crate::{models::admin_action::AdminAction, Result};
+
+#[async_trait]
+pub trait AbstractAdminAction: Sync + Send {
+    async fn insert_admin_action(&self, action: &AdminAction) -> Result<()>;
+    async fn fetch_admin_actions(
+        &self,
+        target_id: Option<&str>,
+        before: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<AdminAction>>;
+}
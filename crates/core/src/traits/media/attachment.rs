@@ -3,12 +3,14 @@ use crate::Result;
 
 #[async_trait]
 pub trait AbstractAttachment: Sync + Send {
+    async fn fetch_attachment(&self, tag: &str, file_id: &str) -> Result<File>;
     async fn find_and_use_attachment(
         &self,
         id: &str,
         tag: &str,
         parent_type: &str,
         parent_id: &str,
+        uploader_id: Option<&str>,
     ) -> Result<File>;
     async fn insert_attachment(&self, attachment: &File) -> Result<()>;
     async fn mark_attachment_as_reported(&self, id: &str) -> Result<()>;
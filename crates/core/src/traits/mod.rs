@@ -1,4 +1,5 @@
 mod admin {
+    pub mod admin_action;
     pub mod migrations;
     pub mod stats;
 }
@@ -29,17 +30,21 @@ mod users {
 
 mod safety {
     pub mod report;
+    pub mod server_report;
     pub mod snapshot;
 }
 
 pub mod ratelimiter {
     pub mod ratelimit;
+    pub mod ratelimit_override;
 }
 
 pub mod webhooks {
+    pub mod outbound_webhook;
     pub mod webhook;
 }
 
+pub use admin::admin_action::AbstractAdminAction;
 pub use admin::migrations::AbstractMigrations;
 pub use admin::stats::AbstractStats;
 
@@ -60,10 +65,13 @@ pub use users::user::AbstractUser;
 pub use users::user_settings::AbstractUserSettings;
 
 pub use safety::report::AbstractReport;
+pub use safety::server_report::AbstractServerReport;
 pub use safety::snapshot::AbstractSnapshot;
 
 pub use ratelimiter::ratelimit::AbstractRatelimitEvent;
+pub use ratelimiter::ratelimit_override::AbstractRatelimitOverride;
 
+pub use webhooks::outbound_webhook::AbstractOutboundWebhook;
 pub use webhooks::webhook::AbstractWebhook;
 
 pub trait AbstractDatabase:
@@ -71,6 +79,7 @@ pub trait AbstractDatabase:
     + Send
     + AbstractMigrations
     + AbstractStats
+    + AbstractAdminAction
     + AbstractAttachment
     + AbstractEmoji
     + AbstractChannel
@@ -84,8 +93,11 @@ pub trait AbstractDatabase:
     + AbstractUser
     + AbstractUserSettings
     + AbstractReport
+    + AbstractServerReport
     + AbstractSnapshot
     + AbstractRatelimitEvent
+    + AbstractRatelimitOverride
     + AbstractWebhook
+    + AbstractOutboundWebhook
 {
 }
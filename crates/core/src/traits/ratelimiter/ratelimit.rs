@@ -8,11 +8,11 @@ use std::time::Duration;
 pub trait AbstractRatelimitEvent: Sync + Send {
     async fn insert_ratelimit_event(&self, event: &RatelimitEvent) -> Result<()>;
 
-    async fn has_ratelimited(
+    /// Fetch events of a type for a target within the last period, oldest first
+    async fn fetch_ratelimit_events(
         &self,
         target_id: &str,
         event_type: RatelimitEventType,
         period: Duration,
-        count: usize,
-    ) -> Result<bool>;
+    ) -> Result<Vec<RatelimitEvent>>;
 }
@@ -0,0 +1,8 @@
+use crate::{models::ratelimit_events::ratelimit_override::RatelimitOverride, Result};
+
+#[async_trait]
+pub trait AbstractRatelimitOverride: Sync + Send {
+    async fn fetch_ratelimit_override(&self, id: &str) -> Result<RatelimitOverride>;
+    async fn set_ratelimit_override(&self, ratelimit_override: &RatelimitOverride) -> Result<()>;
+    async fn delete_ratelimit_override(&self, id: &str) -> Result<()>;
+}
@@ -0,0 +1,18 @@
+use crate::models::server_report::{PartialServerReport, ServerReportStatus};
+use crate::models::ServerReport;
+use crate::Result;
+
+#[async_trait]
+pub trait AbstractServerReport: Sync + Send {
+    async fn insert_server_report(&self, report: &ServerReport) -> Result<()>;
+    async fn update_server_report(&self, id: &str, report: &PartialServerReport) -> Result<()>;
+    async fn fetch_server_report(&self, server_id: &str, report_id: &str) -> Result<ServerReport>;
+    async fn fetch_server_reports(
+        &self,
+        server_id: &str,
+        status: Option<ServerReportStatus>,
+        before: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<ServerReport>>;
+    async fn delete_server_reports(&self, server_id: &str) -> Result<()>;
+}
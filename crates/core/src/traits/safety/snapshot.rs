@@ -5,4 +5,5 @@ use crate::Result;
 pub trait AbstractSnapshot: Sync + Send {
     async fn insert_snapshot(&self, snapshot: &Snapshot) -> Result<()>;
     async fn fetch_snapshot(&self, report_id: &str) -> Result<Snapshot>;
+    async fn fetch_snapshots(&self, report_id: &str) -> Result<Vec<Snapshot>>;
 }
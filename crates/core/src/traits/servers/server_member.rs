@@ -15,6 +15,7 @@ pub trait AbstractServerMember: Sync + Send {
     async fn fetch_all_members<'a>(&self, server: &str) -> Result<Vec<Member>>;
     async fn fetch_all_memberships<'a>(&self, user: &str) -> Result<Vec<Member>>;
     async fn fetch_members<'a>(&self, server: &str, ids: &'a [String]) -> Result<Vec<Member>>;
+    async fn fetch_member_ids(&self, server: &str) -> Result<Vec<String>>;
     async fn fetch_member_count(&self, server: &str) -> Result<usize>;
     async fn fetch_server_count(&self, user: &str) -> Result<usize>;
 }
@@ -15,6 +15,7 @@ pub trait AbstractUser: Sync + Send {
     ) -> Result<()>;
     async fn delete_user(&self, id: &str) -> Result<()>;
     async fn fetch_users<'a>(&self, ids: &'a [String]) -> Result<Vec<User>>;
+    async fn fetch_users_with_expired_status(&self) -> Result<Vec<User>>;
     async fn is_username_taken(&self, username: &str) -> Result<bool>;
     async fn fetch_mutual_user_ids(&self, user_a: &str, user_b: &str) -> Result<Vec<String>>;
     async fn fetch_mutual_channel_ids(&self, user_a: &str, user_b: &str) -> Result<Vec<String>>;
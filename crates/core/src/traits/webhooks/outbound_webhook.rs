@@ -0,0 +1,31 @@
+use crate::models::outbound_webhook::{
+    FieldsOutboundWebhook, OutboundWebhook, OutboundWebhookDelivery, PartialOutboundWebhook,
+};
+use crate::Result;
+
+#[async_trait]
+pub trait AbstractOutboundWebhook: Sync + Send {
+    async fn insert_outbound_webhook(&self, webhook: &OutboundWebhook) -> Result<()>;
+    async fn fetch_outbound_webhook(&self, webhook_id: &str) -> Result<OutboundWebhook>;
+    async fn fetch_outbound_webhooks_for_server(
+        &self,
+        server_id: &str,
+    ) -> Result<Vec<OutboundWebhook>>;
+    async fn update_outbound_webhook(
+        &self,
+        webhook_id: &str,
+        partial: &PartialOutboundWebhook,
+        remove: &[FieldsOutboundWebhook],
+    ) -> Result<()>;
+    async fn delete_outbound_webhook(&self, webhook_id: &str) -> Result<()>;
+
+    async fn insert_outbound_webhook_delivery(
+        &self,
+        delivery: &OutboundWebhookDelivery,
+    ) -> Result<()>;
+    async fn fetch_outbound_webhook_deliveries(
+        &self,
+        webhook_id: &str,
+        limit: i64,
+    ) -> Result<Vec<OutboundWebhookDelivery>>;
+}
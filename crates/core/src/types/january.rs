@@ -33,6 +33,32 @@ pub struct Video {
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct HtmlVideo {
+    pub width: isize,
+    pub height: isize,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub enum TwitchType {
+    Channel,
+    Video,
+    Clip,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub enum SpotifyType {
+    Track,
+    Album,
+    Playlist,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+pub enum BandcampType {
+    Album,
+    Track,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
 #[serde(tag = "type")]
 pub enum Special {
     None,
@@ -42,6 +68,28 @@ pub enum Special {
         #[serde(skip_serializing_if = "Option::is_none")]
         timestamp: Option<String>,
     },
+    Twitch {
+        content_type: TwitchType,
+        id: String,
+    },
+    Spotify {
+        content_type: SpotifyType,
+        id: String,
+    },
+    Soundcloud {
+        /// Path of the track or playlist, such as `artist/track`
+        id: String,
+    },
+    Bandcamp {
+        content_type: BandcampType,
+        id: String,
+    },
+    Streamable {
+        id: String,
+    },
+    Vimeo {
+        id: String,
+    },
 }
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
@@ -67,9 +115,24 @@ pub struct Metadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video: Option<Video>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_video: Option<HtmlVideo>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub site_name: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_url: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_url: Option<String>,
 
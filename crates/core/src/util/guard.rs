@@ -0,0 +1,108 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use async_std::net::ToSocketAddrs;
+use reqwest::Url;
+
+use crate::{Error, Result};
+
+/// Check whether an IPv4 address is not publicly routable
+fn is_forbidden_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+
+    ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8 "this network"
+        || a == 0
+        // 100.64.0.0/10 carrier-grade NAT
+        || (a == 100 && (b & 0xC0) == 64)
+        // 192.0.0.0/24 protocol assignments
+        || (a == 192 && b == 0 && c == 0)
+        // 198.18.0.0/15 benchmarking
+        || (a == 198 && (b & 0xFE) == 18)
+        // 240.0.0.0/4 reserved
+        || a >= 240
+}
+
+/// Check whether an IPv6 address is not publicly routable
+fn is_forbidden_ipv6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+
+    // IPv4-mapped and IPv4-compatible addresses embed an IPv4 address.
+    if let Some(ipv4) = ip.to_ipv4() {
+        if ipv4 != Ipv4Addr::new(0, 0, 0, 1) {
+            return is_forbidden_ipv4(ipv4);
+        }
+    }
+
+    // So do NAT64 addresses.
+    if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        let [_, _, _, _, _, _, high, low] = segments;
+        return is_forbidden_ipv4(Ipv4Addr::from(((high as u32) << 16) | low as u32));
+    }
+
+    ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // fc00::/7 unique local
+        || (segments[0] & 0xFE00) == 0xFC00
+        // fe80::/10 link-local
+        || (segments[0] & 0xFFC0) == 0xFE80
+        // fec0::/10 site-local
+        || (segments[0] & 0xFFC0) == 0xFEC0
+        // 2001:db8::/32 documentation
+        || (segments[0] == 0x2001 && segments[1] == 0x0DB8)
+}
+
+/// Check whether an address is not publicly routable
+pub fn is_forbidden_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_forbidden_ipv4(ip),
+        IpAddr::V6(ip) => is_forbidden_ipv6(ip),
+    }
+}
+
+/// Publicly routable destination of a URL
+pub struct PublicTarget {
+    pub host: String,
+    pub port: u16,
+    pub addrs: Vec<IpAddr>,
+}
+
+/// Resolve an HTTP(S) URL, refusing it if any address it resolves to is not publicly routable
+///
+/// Callers should connect to the returned addresses rather than resolving the host again,
+/// so the check cannot be bypassed by a name which changes between lookups.
+pub async fn resolve_public_url(url: &str) -> Result<PublicTarget> {
+    let url = Url::parse(url).map_err(|_| Error::ForbiddenAddress)?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(Error::ForbiddenAddress);
+    }
+
+    let host = url.host_str().ok_or(Error::ForbiddenAddress)?.to_string();
+    let port = url.port_or_known_default().ok_or(Error::ForbiddenAddress)?;
+
+    let addrs: Vec<IpAddr> = match host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        Ok(ip) => vec![ip],
+        Err(_) => (host.as_str(), port)
+            .to_socket_addrs()
+            .await
+            .map_err(|_| Error::ForbiddenAddress)?
+            .map(|addr| addr.ip())
+            .collect(),
+    };
+
+    if addrs.is_empty() || addrs.iter().any(|ip| is_forbidden_ip(*ip)) {
+        return Err(Error::ForbiddenAddress);
+    }
+
+    Ok(PublicTarget { host, port, addrs })
+}
@@ -1,4 +1,5 @@
 pub mod authifier;
+pub mod guard;
 pub mod idempotency;
 pub mod log;
 pub mod r#macro;
@@ -5,7 +5,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::models::{
-    Bot, Channel, Emoji, Invite, Member, Message, Report, Server, ServerBan, User,
+    Bot, Channel, Emoji, Invite, Member, Message, OutboundWebhook, Report, Server, ServerBan, User,
 };
 use crate::presence::presence_is_online;
 use crate::{database::Database, Error, Result};
@@ -71,6 +71,19 @@ impl Ref {
     pub async fn as_report(&self, db: &Database) -> Result<Report> {
         db.fetch_report(&self.id).await
     }
+
+    pub async fn as_outbound_webhook_in(
+        &self,
+        db: &Database,
+        server: &str,
+    ) -> Result<OutboundWebhook> {
+        let webhook = db.fetch_outbound_webhook(&self.id).await?;
+        if webhook.server_id != server {
+            return Err(Error::NotFound);
+        }
+
+        Ok(webhook)
+    }
 }
 
 impl<'r> FromParam<'r> for Ref {
@@ -32,10 +32,16 @@ pub enum Error {
     UnknownMessage,
     CannotEditMessage,
     CannotJoinCall,
+    NotInCall,
     TooManyAttachments,
     TooManyReplies,
     EmptyMessage,
     PayloadTooLarge,
+    FileTooLarge {
+        max: usize,
+    },
+    FileTypeNotAllowed,
+    UnknownTag,
     CannotRemoveYourself,
     GroupTooLarge {
         max: usize,
@@ -59,8 +65,19 @@ pub enum Error {
         max: usize,
     },
 
+    TooManyOutboundWebhooks {
+        max: usize,
+    },
+    ForbiddenAddress,
+
+    OnCooldown {
+        /// Milliseconds until the action is available again
+        retry_after: u64,
+    },
+
     ReachedMaximumBots,
     IsBot,
+    IsNotBot,
     BotIsPrivate,
 
     CannotReportYourself,
@@ -88,6 +105,7 @@ pub enum Error {
     InvalidSession,
     DuplicateNonce,
     VosoUnavailable,
+    LiveKitUnavailable,
     NotFound,
     NoEffect,
     FailedValidation {
@@ -145,9 +163,13 @@ impl<'r> Responder<'r, 'static> for Error {
             Error::UnknownAttachment => Status::BadRequest,
             Error::CannotEditMessage => Status::Forbidden,
             Error::CannotJoinCall => Status::BadRequest,
+            Error::NotInCall => Status::BadRequest,
 
             Error::EmptyMessage => Status::UnprocessableEntity,
             Error::PayloadTooLarge => Status::UnprocessableEntity,
+            Error::FileTooLarge { .. } => Status::PayloadTooLarge,
+            Error::FileTypeNotAllowed => Status::BadRequest,
+            Error::UnknownTag => Status::BadRequest,
             Error::CannotRemoveYourself => Status::BadRequest,
             Error::GroupTooLarge { .. } => Status::Forbidden,
             Error::AlreadyInGroup => Status::Conflict,
@@ -160,12 +182,16 @@ impl<'r> Responder<'r, 'static> for Error {
             Error::TooManyServers { .. } => Status::Forbidden,
             Error::TooManyChannels { .. } => Status::BadRequest,
             Error::TooManyRoles { .. } => Status::BadRequest,
+            Error::TooManyOutboundWebhooks { .. } => Status::BadRequest,
+            Error::ForbiddenAddress => Status::BadRequest,
+            Error::OnCooldown { .. } => Status::TooManyRequests,
             Error::TooManyEmoji => Status::BadRequest,
             Error::TooManyAttachments => Status::BadRequest,
             Error::TooManyReplies => Status::BadRequest,
 
             Error::ReachedMaximumBots => Status::BadRequest,
             Error::IsBot => Status::BadRequest,
+            Error::IsNotBot => Status::BadRequest,
             Error::BotIsPrivate => Status::Forbidden,
 
             Error::CannotReportYourself => Status::BadRequest,
@@ -185,6 +211,7 @@ impl<'r> Responder<'r, 'static> for Error {
             Error::InvalidSession => Status::Unauthorized,
             Error::DuplicateNonce => Status::Conflict,
             Error::VosoUnavailable => Status::BadRequest,
+            Error::LiveKitUnavailable => Status::BadRequest,
             Error::NotFound => Status::NotFound,
             Error::NoEffect => Status::Ok,
             Error::FailedValidation { .. } => Status::BadRequest,
@@ -139,6 +139,122 @@ pub static MONO_CHAT_MAX_MESSAGE_REACTS: Lazy<usize> = Lazy::new(|| {
         .unwrap()
 });
 
+pub static MONO_CHAT_MAX_OUTBOUND_WEBHOOKS: Lazy<usize> = Lazy::new(|| {
+    env::var("MONO_CHAT_MAX_OUTBOUND_WEBHOOKS")
+        .unwrap_or_else(|_| "10".to_string())
+        .parse()
+        .unwrap()
+});
+
+pub static MONO_CHAT_OUTBOUND_WEBHOOK_ATTEMPTS: Lazy<u32> = Lazy::new(|| {
+    env::var("MONO_CHAT_OUTBOUND_WEBHOOK_ATTEMPTS")
+        .unwrap_or_else(|_| "5".to_string())
+        .parse()
+        .unwrap()
+});
+
+pub static MONO_CHAT_OUTBOUND_WEBHOOK_RETENTION_DAYS: Lazy<u64> = Lazy::new(|| {
+    env::var("MONO_CHAT_OUTBOUND_WEBHOOK_RETENTION_DAYS")
+        .unwrap_or_else(|_| "7".to_string())
+        .parse()
+        .unwrap()
+});
+
+pub static MONO_CHAT_RATELIMIT_BACKEND: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_RATELIMIT_BACKEND").unwrap_or_else(|_| "redis".to_string()));
+
+pub static MONO_CHAT_RATELIMITS: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_RATELIMITS").unwrap_or_default());
+
+pub static MONO_CHAT_BOT_RATELIMITS: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_BOT_RATELIMITS").unwrap_or_default());
+
+pub static MONO_CHAT_COOLDOWNS: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_COOLDOWNS").unwrap_or_default());
+
+pub static MONO_CHAT_WS_REPLAY_BUFFER: Lazy<usize> = Lazy::new(|| {
+    env::var("MONO_CHAT_WS_REPLAY_BUFFER")
+        .unwrap_or_else(|_| "500".to_string())
+        .parse()
+        .unwrap()
+});
+
+pub static MONO_CHAT_WS_RESUME_WINDOW: Lazy<u64> = Lazy::new(|| {
+    env::var("MONO_CHAT_WS_RESUME_WINDOW")
+        .unwrap_or_else(|_| "60".to_string())
+        .parse()
+        .unwrap()
+});
+
+pub static MONO_CHAT_WS_HEARTBEAT_INTERVAL: Lazy<u64> = Lazy::new(|| {
+    env::var("MONO_CHAT_WS_HEARTBEAT_INTERVAL")
+        .unwrap_or_else(|_| "30".to_string())
+        .parse()
+        .unwrap()
+});
+
+pub static MONO_CHAT_WS_HEARTBEAT_TIMEOUT: Lazy<u64> = Lazy::new(|| {
+    env::var("MONO_CHAT_WS_HEARTBEAT_TIMEOUT")
+        .unwrap_or_else(|_| "90".to_string())
+        .parse()
+        .unwrap()
+});
+
+pub static MONO_CHAT_MEMBER_LIST_LIMIT: Lazy<usize> = Lazy::new(|| {
+    env::var("MONO_CHAT_MEMBER_LIST_LIMIT")
+        .unwrap_or_else(|_| "1000".to_string())
+        .parse()
+        .unwrap()
+});
+
+/// Transport used for events, `redis` or `local` for a single process
+///
+/// Redis is still required for other state, see [`preflight_redis`].
+pub static MONO_CHAT_EVENT_BUS: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_EVENT_BUS").unwrap_or_else(|_| "redis".to_string()));
+
+pub static MONO_CHAT_VOICE_BACKEND: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_VOICE_BACKEND").unwrap_or_else(|_| "voso".to_string()));
+
+pub static MONO_CHAT_LIVEKIT_URL: Lazy<String> = Lazy::new(|| {
+    env::var("MONO_CHAT_LIVEKIT_URL").unwrap_or_else(|_| "wss://example.com".to_string())
+});
+
+pub static MONO_CHAT_LIVEKIT_API_KEY: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_LIVEKIT_API_KEY").unwrap_or_default());
+
+pub static MONO_CHAT_LIVEKIT_API_SECRET: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_LIVEKIT_API_SECRET").unwrap_or_default());
+
+pub static MONO_CHAT_LIVEKIT_TOKEN_TTL: Lazy<u64> = Lazy::new(|| {
+    env::var("MONO_CHAT_LIVEKIT_TOKEN_TTL")
+        .unwrap_or_else(|_| "21600".to_string())
+        .parse()
+        .unwrap()
+});
+
+pub static MONO_CHAT_STORAGE: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_STORAGE").unwrap_or_else(|_| "local".to_string()));
+
+pub static MONO_CHAT_STORAGE_PATH: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_STORAGE_PATH").unwrap_or_else(|_| "./uploads".to_string()));
+
+pub static MONO_CHAT_S3_ENDPOINT: Lazy<String> = Lazy::new(|| {
+    env::var("MONO_CHAT_S3_ENDPOINT").unwrap_or_else(|_| "http://localhost:9000".to_string())
+});
+
+pub static MONO_CHAT_S3_REGION: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()));
+
+pub static MONO_CHAT_S3_BUCKET: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_S3_BUCKET").unwrap_or_else(|_| "uploads".to_string()));
+
+pub static MONO_CHAT_S3_ACCESS_KEY_ID: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_S3_ACCESS_KEY_ID").unwrap_or_default());
+
+pub static MONO_CHAT_S3_SECRET_ACCESS_KEY: Lazy<String> =
+    Lazy::new(|| env::var("MONO_CHAT_S3_SECRET_ACCESS_KEY").unwrap_or_default());
+
 pub fn preflight_checks() {
     format!("url = {}", *APP_URL);
     format!("public = {}", *PUBLIC_URL);
@@ -163,6 +279,36 @@ pub fn preflight_checks() {
         #[cfg(debug_assertions)]
         warn!("No Captcha key specified! Remember to add hCaptcha key.");
     }
+
+    if *MONO_CHAT_VOICE_BACKEND == "livekit"
+        && (MONO_CHAT_LIVEKIT_API_KEY.is_empty() || MONO_CHAT_LIVEKIT_API_SECRET.is_empty())
+    {
+        panic!("LiveKit voice backend requires MONO_CHAT_LIVEKIT_API_KEY and MONO_CHAT_LIVEKIT_API_SECRET to be set.");
+    }
+
+    if !matches!(MONO_CHAT_STORAGE.as_str(), "local" | "s3") {
+        panic!(
+            "Unknown file storage `{}`, expected `local` or `s3`.",
+            *MONO_CHAT_STORAGE
+        );
+    }
+}
+
+/// Check that Redis can be reached, panicking otherwise
+///
+/// Presence, sessions, ratelimits, interactions, stream tickets and voice
+/// state all live in Redis, so it is required even with `MONO_CHAT_EVENT_BUS=local`.
+pub async fn preflight_redis() {
+    use redis_kiss::{get_connection, AsyncCommands};
+
+    let reachable = match get_connection().await {
+        Ok(mut conn) => conn.exists::<_, bool>("preflight").await.is_ok(),
+        Err(_) => false,
+    };
+
+    if !reachable {
+        panic!("Redis is required but could not be reached, check REDIS_URI.");
+    }
 }
 
 pub static IS_STAGING: Lazy<bool> =
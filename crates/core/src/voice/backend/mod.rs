@@ -0,0 +1,121 @@
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::{Channel, User, VoiceState},
+    permissions::defn::ChannelPermission,
+    perms,
+    variables::delta::MONO_CHAT_VOICE_BACKEND,
+    Database, Error, Result,
+};
+
+mod livekit;
+mod voso;
+
+pub use self::livekit::LiveKitBackend;
+pub use self::voso::VosoBackend;
+
+/// Credentials a client uses to connect to a call
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+pub struct VoiceCredentials {
+    /// Token to authenticate with the voice server
+    pub token: String,
+    /// Url of the voice server to connect to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// What a user may do once connected to a call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoiceGrants {
+    /// Whether the user may publish audio
+    pub speak: bool,
+    /// Whether the user may publish video and share their screen
+    pub video: bool,
+    /// Whether the user may hear others in the call
+    pub listen: bool,
+}
+
+impl VoiceGrants {
+    /// Work out what a user may do in the call in a channel, given any voice state they have
+    ///
+    /// Server mutes and deafens carry over when rejoining or moving between calls.
+    pub async fn for_user(
+        db: &Database,
+        user: &User,
+        channel: &Channel,
+        state: Option<&VoiceState>,
+    ) -> Result<VoiceGrants> {
+        let mut permissions = perms(user).channel(channel);
+
+        Ok(VoiceGrants {
+            speak: !state.is_some_and(|state| state.server_mute)
+                && permissions
+                    .has_permission(db, ChannelPermission::Speak)
+                    .await?,
+            video: permissions
+                .has_permission(db, ChannelPermission::Video)
+                .await?,
+            listen: !state.is_some_and(|state| state.server_deaf),
+        })
+    }
+}
+
+/// Participant joining or leaving a call, as reported by the voice server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParticipantEvent {
+    Joined { channel_id: String, user_id: String },
+    Left { channel_id: String, user_id: String },
+}
+
+/// Voice server which clients are handed off to when joining a call
+#[async_trait]
+pub trait VoiceBackend: Sync + Send {
+    /// Create credentials for a user to join the call in a channel
+    async fn create_credentials(
+        &self,
+        channel_id: &str,
+        user_id: &str,
+        grants: VoiceGrants,
+    ) -> Result<VoiceCredentials>;
+
+    /// Change what a user connected to the call in a channel may do
+    async fn update_participant(
+        &self,
+        channel_id: &str,
+        user_id: &str,
+        grants: VoiceGrants,
+    ) -> Result<()>;
+
+    /// Remove a user from the call in a channel, they must fetch new credentials to rejoin
+    async fn remove_participant(&self, channel_id: &str, user_id: &str) -> Result<()>;
+
+    /// Whether participants joining and leaving are reported through [`VoiceBackend::parse_event`]
+    ///
+    /// Otherwise users are considered connected as soon as credentials are issued.
+    fn reports_participants(&self) -> bool {
+        false
+    }
+
+    /// Verify and parse an event sent by the voice server, returning `None` for events we ignore
+    fn parse_event(&self, _authorization: &str, _body: &[u8]) -> Result<Option<ParticipantEvent>> {
+        Err(Error::NotFound)
+    }
+}
+
+static VOICE_BACKEND: Lazy<Box<dyn VoiceBackend>> = Lazy::new(create_voice_backend);
+
+/// Create the voice backend selected by `MONO_CHAT_VOICE_BACKEND`
+fn create_voice_backend() -> Box<dyn VoiceBackend> {
+    match MONO_CHAT_VOICE_BACKEND.as_str() {
+        "voso" => Box::new(VosoBackend),
+        "livekit" => Box::new(LiveKitBackend::from_env()),
+        other => panic!("Unknown voice backend `{other}`, expected `voso` or `livekit`."),
+    }
+}
+
+/// Voice backend configured for this process
+pub fn voice_backend() -> &'static dyn VoiceBackend {
+    VOICE_BACKEND.as_ref()
+}
@@ -0,0 +1,85 @@
+use crate::{
+    variables::delta::{VOSO_MANAGE_TOKEN, VOSO_URL},
+    Error, Result,
+};
+
+use super::{VoiceBackend, VoiceCredentials, VoiceGrants};
+
+/// Voso voice server, which creates rooms and users over HTTP
+///
+/// Voso has no notion of grants and cannot remove participants, anyone who
+/// joins may speak until they leave. Moderation only changes the voice state
+/// shown to other users.
+pub struct VosoBackend;
+
+#[async_trait]
+impl VoiceBackend for VosoBackend {
+    async fn create_credentials(
+        &self,
+        channel_id: &str,
+        user_id: &str,
+        _grants: VoiceGrants,
+    ) -> Result<VoiceCredentials> {
+        let client = reqwest::Client::new();
+        let result = client
+            .get(&format!("{}/room/{}", *VOSO_URL, channel_id))
+            .header(
+                reqwest::header::AUTHORIZATION,
+                VOSO_MANAGE_TOKEN.to_string(),
+            )
+            .send()
+            .await;
+
+        match result {
+            Err(_) => return Err(Error::VosoUnavailable),
+            Ok(result) => match result.status() {
+                reqwest::StatusCode::OK => (),
+                reqwest::StatusCode::NOT_FOUND => {
+                    if (client
+                        .post(&format!("{}/room/{}", *VOSO_URL, channel_id))
+                        .header(
+                            reqwest::header::AUTHORIZATION,
+                            VOSO_MANAGE_TOKEN.to_string(),
+                        )
+                        .send()
+                        .await)
+                        .is_err()
+                    {
+                        return Err(Error::VosoUnavailable);
+                    }
+                }
+                _ => return Err(Error::VosoUnavailable),
+            },
+        }
+
+        if let Ok(response) = client
+            .post(&format!(
+                "{}/room/{}/user/{}",
+                *VOSO_URL, channel_id, user_id
+            ))
+            .header(
+                reqwest::header::AUTHORIZATION,
+                VOSO_MANAGE_TOKEN.to_string(),
+            )
+            .send()
+            .await
+        {
+            response.json().await.map_err(|_| Error::InvalidOperation)
+        } else {
+            Err(Error::VosoUnavailable)
+        }
+    }
+
+    async fn update_participant(
+        &self,
+        _channel_id: &str,
+        _user_id: &str,
+        _grants: VoiceGrants,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn remove_participant(&self, _channel_id: &str, _user_id: &str) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use redis_kiss::get_connection;
+
+pub mod backend;
+mod operations;
+
+use operations::{
+    __add_to_set_channel, __delete_key_voice_state, __get_key_voice_state, __get_key_voice_states,
+    __get_set_channel, __remove_from_set_channel, __set_key_voice_state,
+};
+
+use crate::{events::client::EventV1, models::VoiceState};
+
+/// Fetch the voice state of a user, if they are in a call
+pub async fn voice_fetch_state(user_id: &str) -> Option<VoiceState> {
+    let mut conn = get_connection().await.ok()?;
+    __get_key_voice_state(&mut conn, user_id).await
+}
+
+/// Fetch the voice states of everyone connected to any of the given channels
+pub async fn voice_fetch_channel_states(channel_ids: &[String]) -> Vec<VoiceState> {
+    let mut states = vec![];
+
+    if let Ok(mut conn) = get_connection().await {
+        let mut user_ids = vec![];
+        for channel_id in channel_ids {
+            user_ids.append(&mut __get_set_channel(&mut conn, channel_id).await);
+        }
+
+        // Channel sets may briefly lag behind a user moving between calls.
+        states = __get_key_voice_states(&mut conn, &user_ids)
+            .await
+            .into_iter()
+            .filter(|state| channel_ids.contains(&state.channel))
+            .collect();
+    }
+
+    states
+}
+
+/// Connect a user to a channel, leaving any call they were previously in
+///
+/// Mute and deafen flags carry over when moving between calls.
+pub async fn voice_connect(user_id: &str, channel_id: &str) -> VoiceState {
+    let previous = voice_fetch_state(user_id).await;
+    if let Some(state) = &previous {
+        if state.channel == channel_id {
+            return state.clone();
+        }
+    }
+
+    let mut state = previous
+        .clone()
+        .unwrap_or_else(|| VoiceState::new(user_id.to_string(), channel_id.to_string()));
+    state.channel = channel_id.to_string();
+
+    if let Ok(mut conn) = get_connection().await {
+        if let Some(previous) = &previous {
+            __remove_from_set_channel(&mut conn, &previous.channel, user_id).await;
+        }
+
+        __set_key_voice_state(&mut conn, &state).await;
+        __add_to_set_channel(&mut conn, channel_id, user_id).await;
+    }
+
+    if let Some(previous) = previous {
+        EventV1::VoiceStateDelete {
+            id: previous.id,
+            channel: previous.channel.clone(),
+        }
+        .p(previous.channel)
+        .await;
+    }
+
+    EventV1::VoiceStateUpdate(state.clone())
+        .p(state.channel.clone())
+        .await;
+
+    state
+}
+
+/// Save changes to the flags of a user who is in a call
+pub async fn voice_update_state(state: &VoiceState) {
+    if let Ok(mut conn) = get_connection().await {
+        __set_key_voice_state(&mut conn, state).await;
+    }
+
+    EventV1::VoiceStateUpdate(state.clone())
+        .p(state.channel.clone())
+        .await;
+}
+
+/// Disconnect a user from whichever call they are in
+///
+/// Returns the voice state they had, if they were in a call.
+pub async fn voice_disconnect(user_id: &str) -> Option<VoiceState> {
+    let mut conn = get_connection().await.ok()?;
+    let state = __get_key_voice_state(&mut conn, user_id).await?;
+
+    __delete_key_voice_state(&mut conn, user_id).await;
+    __remove_from_set_channel(&mut conn, &state.channel, user_id).await;
+
+    EventV1::VoiceStateDelete {
+        id: state.id.clone(),
+        channel: state.channel.clone(),
+    }
+    .p(state.channel.clone())
+    .await;
+
+    Some(state)
+}
@@ -0,0 +1,69 @@
+use redis_kiss::{AsyncCommands, Conn};
+
+use crate::models::VoiceState;
+
+/// Key holding a user's voice state
+fn state_key(user_id: &str) -> String {
+    format!("voice_state:{user_id}")
+}
+
+/// Key holding the set of users connected to a channel
+fn channel_key(channel_id: &str) -> String {
+    format!("voice_channel:{channel_id}")
+}
+
+/// Set voice state for the given user
+pub async fn __set_key_voice_state(conn: &mut Conn, state: &VoiceState) {
+    let _: Option<()> = conn
+        .set(state_key(&state.id), serde_json::to_string(state).unwrap())
+        .await
+        .ok();
+}
+
+/// Delete voice state for the given user
+pub async fn __delete_key_voice_state(conn: &mut Conn, user_id: &str) {
+    let _: Option<()> = conn.del(state_key(user_id)).await.ok();
+}
+
+/// Get voice state for the given user
+pub async fn __get_key_voice_state(conn: &mut Conn, user_id: &str) -> Option<VoiceState> {
+    conn.get::<_, Option<String>>(state_key(user_id))
+        .await
+        .ok()
+        .flatten()
+        .and_then(|state| serde_json::from_str(&state).ok())
+}
+
+/// Get voice states for many users, skipping any which are missing
+pub async fn __get_key_voice_states(conn: &mut Conn, user_ids: &[String]) -> Vec<VoiceState> {
+    let keys: Vec<String> = user_ids.iter().map(|id| state_key(id)).collect();
+
+    // Redis does not like MGET with a single key.
+    let data: Vec<Option<String>> = match keys.len() {
+        0 => vec![],
+        1 => vec![conn.get(&keys[0]).await.unwrap_or_default()],
+        _ => conn.get(keys).await.unwrap_or_default(),
+    };
+
+    data.into_iter()
+        .flatten()
+        .filter_map(|state| serde_json::from_str(&state).ok())
+        .collect()
+}
+
+/// Add to channel user set
+pub async fn __add_to_set_channel(conn: &mut Conn, channel_id: &str, user_id: &str) {
+    let _: Option<()> = conn.sadd(channel_key(channel_id), user_id).await.ok();
+}
+
+/// Remove from channel user set
+pub async fn __remove_from_set_channel(conn: &mut Conn, channel_id: &str, user_id: &str) {
+    let _: Option<()> = conn.srem(channel_key(channel_id), user_id).await.ok();
+}
+
+/// Get channel user set as list
+pub async fn __get_set_channel(conn: &mut Conn, channel_id: &str) -> Vec<String> {
+    conn.smembers::<_, Vec<String>>(channel_key(channel_id))
+        .await
+        .unwrap_or_default()
+}
@@ -0,0 +1,34 @@
+#[macro_use]
+extern crate lazy_static;
+
+use actix_web::dev::Server;
+use actix_web::middleware::Logger;
+use actix_web::{web, App, HttpServer};
+use util::variables::JANUARY_PUBLIC_URL;
+
+pub mod routes;
+pub mod structs;
+pub mod util;
+
+/// Start serving embeds and the media proxy on `JANUARY_PUBLIC_URL`
+///
+/// Must be called from within an actix system.
+/// Fails if the oEmbed provider list cannot be loaded.
+pub fn server() -> std::io::Result<Server> {
+    structs::oembed::load_providers()?;
+
+    Ok(HttpServer::new(|| {
+        App::new()
+            .wrap(Logger::default())
+            .route("/", web::get().to(routes::info::get))
+            .route("/embed", web::get().to(routes::embed::get))
+            .route("/proxy", web::get().to(routes::proxy::get))
+    })
+    .bind(JANUARY_PUBLIC_URL.clone())?
+    .run())
+}
+
+/// Run the server on a new actix system, blocking the current thread
+pub fn run() -> std::io::Result<()> {
+    actix_web::rt::System::new().block_on(async { server()?.await })
+}
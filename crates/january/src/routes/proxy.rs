@@ -1,11 +1,19 @@
 use std::time::Duration;
 
-use actix_web::web::Bytes;
+use actix_web::web::{self, Bytes};
 use actix_web::{web::Query, HttpResponse, Responder};
 use serde::Deserialize;
 
 use crate::util::request::{fetch, get_bytes};
+use crate::util::resize::{process, OutputFormat, Processed};
 use crate::util::result::Error;
+use crate::util::variables::MAX_RESIZE_DIMENSION;
+
+/// Processed image and its content type, None if it was passed through unchanged
+type Variant = (Bytes, Option<&'static str>);
+
+/// Cache key for processed images
+type VariantKey = (String, Option<u32>, Option<u32>, OutputFormat);
 
 lazy_static! {
     static ref CACHE: moka::future::Cache<String, Result<Bytes, Error>> =
@@ -16,11 +24,25 @@ lazy_static! {
             .max_capacity(1024 * 1024 * 1024)
             .time_to_live(Duration::from_secs(60))
             .build();
+    static ref VARIANT_CACHE: moka::future::Cache<VariantKey, Result<Variant, Error>> =
+        moka::future::Cache::builder()
+            .weigher(|_key, value: &Result<Variant, Error>| {
+                value
+                    .as_ref()
+                    .map(|(bytes, _)| bytes.len() as u32)
+                    .unwrap_or(1)
+            })
+            .max_capacity(256 * 1024 * 1024)
+            .time_to_live(Duration::from_secs(300))
+            .build();
 }
 
 #[derive(Deserialize)]
 pub struct Parameters {
     url: String,
+    width: Option<u32>,
+    height: Option<u32>,
+    format: Option<OutputFormat>,
 }
 
 async fn proxy(url: String) -> Result<Bytes, Error> {
@@ -34,11 +56,62 @@ async fn proxy(url: String) -> Result<Bytes, Error> {
     }
 }
 
+async fn proxy_cached(url: String) -> Result<Bytes, Error> {
+    CACHE
+        .get_with(url.clone(), async { proxy(url).await })
+        .await
+}
+
+async fn resize(
+    url: String,
+    width: Option<u32>,
+    height: Option<u32>,
+    format: OutputFormat,
+) -> Result<Variant, Error> {
+    let original = proxy_cached(url).await?;
+
+    let bytes = original.clone();
+    let processed = web::block(move || process(&bytes, width, height, format))
+        .await
+        .map_err(|_| Error::ConversionFailed)??;
+
+    Ok(match processed {
+        Processed::Image(data, content_type) => (Bytes::from(data), Some(content_type)),
+        Processed::Original => (original, None),
+    })
+}
+
 pub async fn get(Query(info): Query<Parameters>) -> Result<impl Responder, Error> {
-    let url = info.url;
+    let Parameters {
+        url,
+        width,
+        height,
+        format,
+    } = info;
 
-    let result = CACHE
-        .get_with(url.clone(), async { proxy(url).await })
-        .await;
-    result.map(|b| HttpResponse::Ok().body(b))
+    if width.is_none() && height.is_none() && format.is_none() {
+        return proxy_cached(url).await.map(|b| HttpResponse::Ok().body(b));
+    }
+
+    if [width, height]
+        .into_iter()
+        .flatten()
+        .any(|dimension| dimension == 0 || dimension > *MAX_RESIZE_DIMENSION)
+    {
+        return Err(Error::InvalidDimensions);
+    }
+
+    let format = format.unwrap_or(OutputFormat::Webp);
+    let (bytes, content_type) = VARIANT_CACHE
+        .get_with((url.clone(), width, height, format), async {
+            resize(url, width, height, format).await
+        })
+        .await?;
+
+    let mut response = HttpResponse::Ok();
+    if let Some(content_type) = content_type {
+        response.content_type(content_type);
+    }
+
+    Ok(response.body(bytes))
 }
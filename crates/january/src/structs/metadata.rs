@@ -1,15 +1,21 @@
 use std::collections::HashMap;
 
-use chat_core::types::january::{Image, ImageSize, Special, Video};
+use chat_core::types::january::{HtmlVideo, Image, ImageSize, Special, Video};
 use regex::Regex;
-use reqwest::Response;
-use scraper::Selector;
+use reqwest::{Response, Url};
+use scraper::{Html, Selector};
 use serde::Serialize;
 use validator::Validate;
 
-use crate::util::{
-    request::{consume_fragment, consume_size, fetch},
-    result::Error,
+use crate::{
+    structs::{
+        oembed::{find_provider_endpoint, OEmbed},
+        special,
+    },
+    util::{
+        request::{consume_fragment, consume_size, fetch},
+        result::Error,
+    },
 };
 
 #[derive(Clone, Validate, Debug, Serialize)]
@@ -32,23 +38,46 @@ pub struct Metadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub video: Option<Video>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_video: Option<HtmlVideo>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub opengraph_type: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub site_name: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_url: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_url: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub colour: Option<String>,
+
+    /// oEmbed endpoint advertised by the page
+    #[serde(skip)]
+    pub oembed_url: Option<String>,
 }
 
 impl Metadata {
     pub async fn from(resp: Response, original_url: String) -> Result<Metadata, Error> {
         let fragment = consume_fragment(resp).await?;
+        Metadata::parse(&fragment, original_url)
+    }
 
+    /// Extract metadata from a page
+    pub fn parse(fragment: &Html, original_url: String) -> Result<Metadata, Error> {
         let metadata_selector = Selector::parse("meta").map_err(|_| Error::MetaSelectionFailed)?;
 
         let mut meta = HashMap::new();
@@ -66,10 +95,21 @@ impl Metadata {
 
         let link_selector = Selector::parse("link").map_err(|_| Error::MetaSelectionFailed)?;
         let mut link = HashMap::new();
+        let mut oembed_url = None;
         for el in fragment.select(&link_selector) {
             let node = el.value();
 
             if let (Some(property), Some(content)) = (node.attr("rel"), node.attr("href")) {
+                if oembed_url.is_none()
+                    && property.split_whitespace().any(|rel| rel == "alternate")
+                    && node.attr("type") == Some("application/json+oembed")
+                {
+                    oembed_url = Url::parse(&original_url)
+                        .and_then(|base| base.join(content))
+                        .ok()
+                        .map(|url| url.to_string());
+                }
+
                 link.insert(property.to_string(), content.to_string());
             }
         }
@@ -162,6 +202,12 @@ impl Metadata {
                 .unwrap_or_else(|| original_url.clone()),
             original_url,
             special: None,
+            html_video: None,
+            author_name: None,
+            author_url: None,
+            provider_name: None,
+            provider_url: None,
+            oembed_url,
         };
         metadata
             .validate()
@@ -184,6 +230,82 @@ impl Metadata {
         Ok(())
     }
 
+    /// Merge an oEmbed response into this metadata, keeping anything the page already provided
+    pub fn apply_oembed(&mut self, oembed: OEmbed) {
+        if self.title.is_none() {
+            self.title = oembed.title;
+        }
+
+        if self.site_name.is_none() {
+            self.site_name = oembed.provider_name.clone();
+        }
+
+        self.author_name = oembed.author_name;
+        self.author_url = oembed.author_url;
+        self.provider_name = oembed.provider_name;
+        self.provider_url = oembed.provider_url;
+
+        match oembed.kind.as_str() {
+            "photo" => {
+                if let (Some(url), Some(width), Some(height)) =
+                    (oembed.url, oembed.width, oembed.height)
+                {
+                    if self.image.is_none() {
+                        self.image = Some(Image {
+                            url,
+                            width,
+                            height,
+                            size: ImageSize::Large,
+                        });
+                    }
+                }
+            }
+            "video" | "rich" => {
+                if let (true, Some(width), Some(height)) =
+                    (oembed.html.is_some(), oembed.width, oembed.height)
+                {
+                    self.html_video = Some(HtmlVideo { width, height });
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(url) = oembed.thumbnail_url {
+            let (width, height) = (
+                oembed.thumbnail_width.unwrap_or(0),
+                oembed.thumbnail_height.unwrap_or(0),
+            );
+
+            match &mut self.image {
+                Some(image) => {
+                    if image.url == url && (image.width == 0 || image.height == 0) {
+                        image.width = width;
+                        image.height = height;
+                    }
+                }
+                None => {
+                    self.image = Some(Image {
+                        url,
+                        width,
+                        height,
+                        size: ImageSize::Preview,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Fetch oEmbed data from a known provider or the endpoint advertised by the page
+    async fn resolve_oembed(&mut self) -> Result<(), Error> {
+        let endpoint = find_provider_endpoint(&self.original_url)
+            .or_else(|| self.oembed_url.clone())
+            .ok_or(Error::NoOEmbedEndpoint)?;
+
+        let oembed = OEmbed::fetch(&endpoint).await?;
+        self.apply_oembed(oembed);
+        Ok(())
+    }
+
     pub async fn generate_special(&mut self) -> Result<Special, Error> {
         lazy_static! {
             static ref RE_YOUTUBE: Regex = Regex::new("^(?:(?:https?:)?//)?(?:(?:www|m)\\.)?(?:(?:youtube\\.com|youtu.be))(?:/(?:[\\w\\-]+\\?v=|embed/|v/)?)([\\w\\-]+)(?:\\S+)?$").unwrap();
@@ -211,12 +333,24 @@ impl Metadata {
             }
         } else if RE_GIF.is_match(&self.original_url) {
             return Ok(Special::GIF);
+        } else {
+            let video_url = self.video.as_ref().map(|video| video.url.as_str());
+            if let Some(special) = special::detect(&self.original_url, video_url)
+                .or_else(|| special::detect(&self.url, video_url))
+            {
+                return Ok(special);
+            }
         }
 
         Ok(Special::None)
     }
 
     pub async fn resolve_external(&mut self) {
+        match self.resolve_oembed().await {
+            Ok(()) | Err(Error::NoOEmbedEndpoint) => {}
+            Err(err) => log::debug!("Failed to resolve oEmbed for {}: {err:?}", self.url),
+        }
+
         if let Ok(special) = self.generate_special().await {
             self.colour = Some("#FF424F".to_string());
             self.special = Some(special);
@@ -245,9 +379,164 @@ impl From<Metadata> for chat_core::types::january::Metadata {
             description: value.description,
             image: value.image,
             video: value.video,
+            html_video: value.html_video,
             site_name: value.site_name,
+            author_name: value.author_name,
+            author_url: value.author_url,
+            provider_name: value.provider_name,
+            provider_url: value.provider_url,
             icon_url: value.icon_url,
             colour: value.colour,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use chat_core::types::january::ImageSize;
+    use scraper::Html;
+
+    use super::Metadata;
+    use crate::{structs::oembed::OEmbed, util::request::fetch};
+
+    /// Serve the discovery page and its oEmbed response from a local listener
+    ///
+    /// Returns the address of the listener, which stops after `requests` requests.
+    fn serve(requests: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+
+                let mut request = vec![];
+                let mut buffer = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let read = stream.read(&mut buffer).unwrap();
+                    if read == 0 {
+                        break;
+                    }
+
+                    request.extend_from_slice(&buffer[..read]);
+                }
+
+                let request = String::from_utf8_lossy(&request);
+                let (content_type, body) = if request.starts_with("GET /oembed?") {
+                    ("application/json", include_str!("fixtures/video.json"))
+                } else {
+                    ("text/html", include_str!("fixtures/discovery.html"))
+                };
+
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        address.to_string()
+    }
+
+    fn parse(fixture: &str) -> Metadata {
+        Metadata::parse(
+            &Html::parse_document(fixture),
+            "https://example.com/watch/1".to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn discovers_json_oembed() {
+        let metadata = parse(include_str!("fixtures/discovery.html"));
+
+        assert_eq!(
+            metadata.oembed_url.as_deref(),
+            Some(
+                "https://example.com/oembed?url=https%3A%2F%2Fexample.com%2Fwatch%2F1&format=json"
+            )
+        );
+        assert_eq!(metadata.title.as_deref(), Some("Example video"));
+    }
+
+    #[test]
+    fn ignores_other_alternates() {
+        let metadata = parse(include_str!("fixtures/no_discovery.html"));
+
+        assert_eq!(metadata.oembed_url, None);
+    }
+
+    #[test]
+    fn applies_video_oembed() {
+        let mut metadata = parse(include_str!("fixtures/discovery.html"));
+        metadata.apply_oembed(OEmbed::parse(include_bytes!("fixtures/video.json")).unwrap());
+
+        // Page metadata is preferred over oEmbed.
+        assert_eq!(metadata.title.as_deref(), Some("Example video"));
+        assert_eq!(metadata.site_name.as_deref(), Some("Example"));
+
+        assert_eq!(metadata.author_name.as_deref(), Some("Some Author"));
+        assert_eq!(
+            metadata.author_url.as_deref(),
+            Some("https://example.com/author")
+        );
+        assert_eq!(metadata.provider_name.as_deref(), Some("Example"));
+
+        let html_video = metadata.html_video.unwrap();
+        assert_eq!((html_video.width, html_video.height), (640, 360));
+
+        let image = metadata.image.unwrap();
+        assert_eq!(image.url, "https://example.com/thumbnail.jpg");
+        assert_eq!((image.width, image.height), (480, 270));
+        assert!(matches!(image.size, ImageSize::Preview));
+    }
+
+    #[test]
+    fn applies_photo_oembed() {
+        let mut metadata = parse(include_str!("fixtures/no_discovery.html"));
+        metadata.apply_oembed(OEmbed::parse(include_bytes!("fixtures/photo.json")).unwrap());
+
+        assert_eq!(metadata.title.as_deref(), Some("Plain page"));
+        assert_eq!(metadata.site_name.as_deref(), Some("Example Photos"));
+        assert!(metadata.html_video.is_none());
+
+        let image = metadata.image.unwrap();
+        assert_eq!(image.url, "https://example.com/photo.png");
+        assert_eq!((image.width, image.height), (1024, 768));
+        assert!(matches!(image.size, ImageSize::Large));
+    }
+
+    #[actix_web::test]
+    async fn resolves_discovered_oembed() {
+        // Loopback addresses are refused unless explicitly allowed.
+        std::env::set_var("JANUARY_ALLOWED_HOSTS", "127.0.0.1");
+
+        let url = format!("http://{}/watch/1", serve(2));
+        let (resp, _) = fetch(&url).await.unwrap();
+        let mut metadata = Metadata::from(resp, url.clone()).await.unwrap();
+
+        assert_eq!(
+            metadata.oembed_url,
+            Some(format!(
+                "{}oembed?url=https%3A%2F%2Fexample.com%2Fwatch%2F1&format=json",
+                url.trim_end_matches("watch/1")
+            ))
+        );
+
+        metadata.resolve_oembed().await.unwrap();
+
+        assert_eq!(metadata.author_name.as_deref(), Some("Some Author"));
+        assert_eq!(metadata.provider_name.as_deref(), Some("Example"));
+
+        let html_video = metadata.html_video.unwrap();
+        assert_eq!((html_video.width, html_video.height), (640, 360));
+    }
+}
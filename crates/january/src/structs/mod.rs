@@ -1 +1,3 @@
 pub mod metadata;
+pub mod oembed;
+pub mod special;
@@ -0,0 +1,176 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    sync::OnceLock,
+};
+
+use regex::Regex;
+use reqwest::Url;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::util::{
+    request::{fetch, get_bytes},
+    result::Error,
+    variables::OEMBED_PROVIDERS,
+};
+
+/// Providers used when `JANUARY_OEMBED_PROVIDERS` is not set
+static DEFAULT_PROVIDERS: &str = include_str!("providers.json");
+
+/// Provider registry, loaded once at startup by [`load_providers`]
+static REGISTRY: OnceLock<ProviderRegistry> = OnceLock::new();
+
+/// oEmbed provider, in the format used by oembed.com's `providers.json`
+#[derive(Deserialize, Debug, Clone)]
+pub struct Provider {
+    pub provider_name: String,
+    pub endpoints: Vec<ProviderEndpoint>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProviderEndpoint {
+    #[serde(default)]
+    pub schemes: Vec<String>,
+    pub url: String,
+}
+
+/// Known oEmbed endpoints, matched against page URLs
+pub struct ProviderRegistry {
+    endpoints: Vec<(Regex, String)>,
+}
+
+impl ProviderRegistry {
+    /// Parse a JSON list of providers
+    pub fn parse(source: &str) -> Result<ProviderRegistry, serde_json::Error> {
+        let providers: Vec<Provider> = serde_json::from_str(source)?;
+
+        Ok(ProviderRegistry {
+            endpoints: providers
+                .into_iter()
+                .flat_map(|provider| provider.endpoints)
+                .flat_map(|endpoint| {
+                    let url = endpoint.url.replace("{format}", "json");
+                    endpoint
+                        .schemes
+                        .into_iter()
+                        .filter_map(move |scheme| Some((scheme_to_regex(&scheme)?, url.clone())))
+                })
+                .collect(),
+        })
+    }
+
+    /// Find the oEmbed request URL for a page
+    pub fn find(&self, url: &str) -> Option<String> {
+        self.endpoints
+            .iter()
+            .find(|(scheme, _)| scheme.is_match(url))
+            .and_then(|(_, endpoint)| {
+                Url::parse_with_params(endpoint, &[("url", url), ("format", "json")]).ok()
+            })
+            .map(|url| url.to_string())
+    }
+}
+
+/// Convert a provider URL scheme, where `*` matches anything, into a regex
+fn scheme_to_regex(scheme: &str) -> Option<Regex> {
+    let pattern = scheme
+        .split('*')
+        .map(regex::escape)
+        .collect::<Vec<String>>()
+        .join(".*");
+
+    Regex::new(&format!("^{pattern}$")).ok()
+}
+
+/// Read and validate the configured providers, failing if they cannot be used
+pub fn load_providers() -> io::Result<()> {
+    let source = match OEMBED_PROVIDERS.as_ref() {
+        Some(path) => fs::read_to_string(path)?,
+        None => DEFAULT_PROVIDERS.to_string(),
+    };
+
+    let registry = ProviderRegistry::parse(&source)
+        .map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+
+    REGISTRY.get_or_init(|| registry);
+    Ok(())
+}
+
+/// Find the oEmbed request URL for a page from the configured providers
+pub fn find_provider_endpoint(url: &str) -> Option<String> {
+    REGISTRY.get()?.find(url)
+}
+
+/// Accept sizes given as either numbers or numeric strings
+fn lenient_size<'de, D>(deserializer: D) -> Result<Option<isize>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<Value>::deserialize(deserializer)? {
+        Some(Value::Number(number)) => number.as_f64().map(|value| value as isize),
+        Some(Value::String(value)) => value.trim().parse().ok(),
+        _ => None,
+    })
+}
+
+/// oEmbed response
+///
+/// The `html` field is never passed on to clients.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct OEmbed {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub title: Option<String>,
+    pub author_name: Option<String>,
+    pub author_url: Option<String>,
+    pub provider_name: Option<String>,
+    pub provider_url: Option<String>,
+    pub url: Option<String>,
+    pub html: Option<String>,
+    #[serde(default, deserialize_with = "lenient_size")]
+    pub width: Option<isize>,
+    #[serde(default, deserialize_with = "lenient_size")]
+    pub height: Option<isize>,
+    pub thumbnail_url: Option<String>,
+    #[serde(default, deserialize_with = "lenient_size")]
+    pub thumbnail_width: Option<isize>,
+    #[serde(default, deserialize_with = "lenient_size")]
+    pub thumbnail_height: Option<isize>,
+}
+
+impl OEmbed {
+    /// Parse an oEmbed JSON response
+    pub fn parse(body: &[u8]) -> Result<OEmbed, Error> {
+        serde_json::from_slice(body).map_err(|_| Error::ConversionFailed)
+    }
+
+    /// Fetch an oEmbed response
+    pub async fn fetch(url: &str) -> Result<OEmbed, Error> {
+        let (mut resp, _) = fetch(url).await?;
+        let bytes = get_bytes(&mut resp).await?;
+        OEmbed::parse(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProviderRegistry, DEFAULT_PROVIDERS};
+
+    #[test]
+    fn default_providers_are_valid() {
+        let registry = ProviderRegistry::parse(DEFAULT_PROVIDERS).unwrap();
+
+        assert_eq!(
+            registry.find("https://vimeo.com/76979871").as_deref(),
+            Some("https://vimeo.com/api/oembed.json?url=https%3A%2F%2Fvimeo.com%2F76979871&format=json")
+        );
+        assert_eq!(registry.find("https://example.com/"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_providers() {
+        assert!(ProviderRegistry::parse("{}").is_err());
+        assert!(ProviderRegistry::parse(r#"[{ "provider_name": "Missing endpoints" }]"#).is_err());
+    }
+}
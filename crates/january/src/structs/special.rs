@@ -0,0 +1,335 @@
+use chat_core::types::january::{BandcampType, Special, SpotifyType, TwitchType};
+use regex::Regex;
+
+lazy_static! {
+    static ref RE_TWITCH_CLIP: Regex = Regex::new("^(?:https?://)?(?:clips\\.twitch\\.tv/|(?:www\\.|m\\.)?twitch\\.tv/\\w+/clip/)([\\w\\-]+)").unwrap();
+    static ref RE_TWITCH_VIDEO: Regex = Regex::new("^(?:https?://)?(?:www\\.|m\\.)?twitch\\.tv/videos/(\\d+)").unwrap();
+    static ref RE_TWITCH_CHANNEL: Regex = Regex::new("^(?:https?://)?(?:www\\.|m\\.|go\\.)?twitch\\.tv/(\\w+)/?(?:[?#]|$)").unwrap();
+    static ref RE_SPOTIFY: Regex = Regex::new("^(?:https?://)?open\\.spotify\\.com/(?:intl-[\\w\\-]+/)?(track|album|playlist)/(\\w+)").unwrap();
+    static ref RE_SOUNDCLOUD: Regex = Regex::new("^(?:https?://)?(?:www\\.|m\\.)?soundcloud\\.com/([\\w\\-]+)/((?:sets/)?[\\w\\-]+)").unwrap();
+    static ref RE_BANDCAMP: Regex = Regex::new("^(?:https?://)?(?:[\\w\\-]+\\.)?bandcamp\\.com/EmbeddedPlayer/(?:v=\\d+/)?(album|track)=(\\d+)").unwrap();
+    static ref RE_STREAMABLE: Regex = Regex::new("^(?:https?://)?(?:www\\.)?streamable\\.com/(?:[eos]/)?(\\w+)/?(?:[?#]|$)").unwrap();
+    static ref RE_VIMEO: Regex = Regex::new("^(?:https?://)?(?:www\\.|player\\.)?vimeo\\.com/(?:video/|channels/[\\w\\-]+/)?(\\d+)").unwrap();
+}
+
+/// Paths on twitch.tv which are not channels
+static TWITCH_RESERVED: &[&str] = &[
+    "directory",
+    "downloads",
+    "jobs",
+    "p",
+    "search",
+    "settings",
+    "videos",
+];
+
+/// Paths on soundcloud.com which are not users
+static SOUNDCLOUD_RESERVED_USERS: &[&str] = &[
+    "charts",
+    "discover",
+    "jobs",
+    "messages",
+    "notifications",
+    "pages",
+    "search",
+    "settings",
+    "stations",
+    "stream",
+    "tags",
+    "upload",
+    "you",
+];
+
+/// Paths under a SoundCloud user which are not tracks or playlists
+static SOUNDCLOUD_RESERVED_PATHS: &[&str] = &[
+    "albums",
+    "comments",
+    "followers",
+    "following",
+    "likes",
+    "popular-tracks",
+    "reposts",
+    "sets",
+    "spotlight",
+    "tracks",
+];
+
+/// Detect a Twitch channel, video or clip
+pub fn detect_twitch(url: &str) -> Option<Special> {
+    let (content_type, id) = if let Some(captures) = RE_TWITCH_CLIP.captures(url) {
+        (TwitchType::Clip, captures[1].to_string())
+    } else if let Some(captures) = RE_TWITCH_VIDEO.captures(url) {
+        (TwitchType::Video, captures[1].to_string())
+    } else {
+        let captures = RE_TWITCH_CHANNEL.captures(url)?;
+        if TWITCH_RESERVED.contains(&&captures[1]) {
+            return None;
+        }
+
+        (TwitchType::Channel, captures[1].to_string())
+    };
+
+    Some(Special::Twitch { content_type, id })
+}
+
+/// Detect a Spotify track, album or playlist
+pub fn detect_spotify(url: &str) -> Option<Special> {
+    let captures = RE_SPOTIFY.captures(url)?;
+    let content_type = match &captures[1] {
+        "album" => SpotifyType::Album,
+        "playlist" => SpotifyType::Playlist,
+        _ => SpotifyType::Track,
+    };
+
+    Some(Special::Spotify {
+        content_type,
+        id: captures[2].to_string(),
+    })
+}
+
+/// Detect a SoundCloud track or playlist
+///
+/// SoundCloud has no public numeric IDs, so the path is used instead.
+pub fn detect_soundcloud(url: &str) -> Option<Special> {
+    let captures = RE_SOUNDCLOUD.captures(url)?;
+    if SOUNDCLOUD_RESERVED_USERS.contains(&&captures[1])
+        || SOUNDCLOUD_RESERVED_PATHS.contains(&&captures[2])
+    {
+        return None;
+    }
+
+    Some(Special::Soundcloud {
+        id: format!("{}/{}", &captures[1], &captures[2]),
+    })
+}
+
+/// Detect a Bandcamp album or track from its embedded player URL
+///
+/// Bandcamp pages only expose numeric IDs through `og:video`.
+pub fn detect_bandcamp(video_url: &str) -> Option<Special> {
+    let captures = RE_BANDCAMP.captures(video_url)?;
+    let content_type = match &captures[1] {
+        "album" => BandcampType::Album,
+        _ => BandcampType::Track,
+    };
+
+    Some(Special::Bandcamp {
+        content_type,
+        id: captures[2].to_string(),
+    })
+}
+
+/// Detect a Streamable video
+pub fn detect_streamable(url: &str) -> Option<Special> {
+    let captures = RE_STREAMABLE.captures(url)?;
+    Some(Special::Streamable {
+        id: captures[1].to_string(),
+    })
+}
+
+/// Detect a Vimeo video
+pub fn detect_vimeo(url: &str) -> Option<Special> {
+    let captures = RE_VIMEO.captures(url)?;
+    Some(Special::Vimeo {
+        id: captures[1].to_string(),
+    })
+}
+
+/// Detect any of the providers above
+pub fn detect(url: &str, video_url: Option<&str>) -> Option<Special> {
+    detect_twitch(url)
+        .or_else(|| detect_spotify(url))
+        .or_else(|| detect_soundcloud(url))
+        .or_else(|| video_url.and_then(detect_bandcamp))
+        .or_else(|| detect_streamable(url))
+        .or_else(|| detect_vimeo(url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn twitch() {
+        assert_eq!(
+            detect_twitch("https://www.twitch.tv/somechannel"),
+            Some(Special::Twitch {
+                content_type: TwitchType::Channel,
+                id: "somechannel".to_string()
+            })
+        );
+        assert_eq!(
+            detect_twitch("https://www.twitch.tv/videos/123456789"),
+            Some(Special::Twitch {
+                content_type: TwitchType::Video,
+                id: "123456789".to_string()
+            })
+        );
+        assert_eq!(
+            detect_twitch("https://clips.twitch.tv/FunnyClip-abc_123"),
+            Some(Special::Twitch {
+                content_type: TwitchType::Clip,
+                id: "FunnyClip-abc_123".to_string()
+            })
+        );
+        assert_eq!(
+            detect_twitch("https://www.twitch.tv/somechannel/clip/FunnyClip"),
+            Some(Special::Twitch {
+                content_type: TwitchType::Clip,
+                id: "FunnyClip".to_string()
+            })
+        );
+
+        assert_eq!(detect_twitch("https://www.twitch.tv/directory"), None);
+        assert_eq!(
+            detect_twitch("https://www.twitch.tv/somechannel/about"),
+            None
+        );
+        assert_eq!(
+            detect_twitch("https://twitch.tv.example.com/somechannel"),
+            None
+        );
+    }
+
+    #[test]
+    fn spotify() {
+        assert_eq!(
+            detect_spotify("https://open.spotify.com/track/4uLU6hMCjMI75M1A2tKUQC"),
+            Some(Special::Spotify {
+                content_type: SpotifyType::Track,
+                id: "4uLU6hMCjMI75M1A2tKUQC".to_string()
+            })
+        );
+        assert_eq!(
+            detect_spotify("https://open.spotify.com/intl-de/album/1DFixLWuPkv3KT3TnV35m3"),
+            Some(Special::Spotify {
+                content_type: SpotifyType::Album,
+                id: "1DFixLWuPkv3KT3TnV35m3".to_string()
+            })
+        );
+        assert_eq!(
+            detect_spotify("https://open.spotify.com/playlist/37i9dQZF1DXcBWIGoYBM5M?si=x"),
+            Some(Special::Spotify {
+                content_type: SpotifyType::Playlist,
+                id: "37i9dQZF1DXcBWIGoYBM5M".to_string()
+            })
+        );
+
+        assert_eq!(
+            detect_spotify("https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF"),
+            None
+        );
+        assert_eq!(
+            detect_spotify("https://spotify.com/track/4uLU6hMCjMI75M1A2tKUQC"),
+            None
+        );
+    }
+
+    #[test]
+    fn soundcloud() {
+        assert_eq!(
+            detect_soundcloud("https://soundcloud.com/artist/some-track"),
+            Some(Special::Soundcloud {
+                id: "artist/some-track".to_string()
+            })
+        );
+        assert_eq!(
+            detect_soundcloud("https://m.soundcloud.com/artist/sets/some-playlist"),
+            Some(Special::Soundcloud {
+                id: "artist/sets/some-playlist".to_string()
+            })
+        );
+
+        assert_eq!(detect_soundcloud("https://soundcloud.com/artist"), None);
+        assert_eq!(
+            detect_soundcloud("https://soundcloud.com/artist/likes"),
+            None
+        );
+        assert_eq!(
+            detect_soundcloud("https://soundcloud.com/artist/sets"),
+            None
+        );
+        assert_eq!(
+            detect_soundcloud("https://soundcloud.com/discover/sets/new-for-you"),
+            None
+        );
+        assert_eq!(
+            detect_soundcloud("https://example.com/soundcloud.com/a/b"),
+            None
+        );
+    }
+
+    #[test]
+    fn bandcamp() {
+        assert_eq!(
+            detect_bandcamp("https://bandcamp.com/EmbeddedPlayer/v=2/album=1234567890/size=large"),
+            Some(Special::Bandcamp {
+                content_type: BandcampType::Album,
+                id: "1234567890".to_string()
+            })
+        );
+        assert_eq!(
+            detect_bandcamp("https://artist.bandcamp.com/EmbeddedPlayer/track=42"),
+            Some(Special::Bandcamp {
+                content_type: BandcampType::Track,
+                id: "42".to_string()
+            })
+        );
+
+        assert_eq!(
+            detect_bandcamp("https://artist.bandcamp.com/album/some-album"),
+            None
+        );
+        assert_eq!(
+            detect_bandcamp("https://bandcamp.com/EmbeddedPlayer/album=abc"),
+            None
+        );
+    }
+
+    #[test]
+    fn streamable() {
+        assert_eq!(
+            detect_streamable("https://streamable.com/abc123"),
+            Some(Special::Streamable {
+                id: "abc123".to_string()
+            })
+        );
+        assert_eq!(
+            detect_streamable("https://streamable.com/e/abc123?autoplay=1"),
+            Some(Special::Streamable {
+                id: "abc123".to_string()
+            })
+        );
+
+        assert_eq!(detect_streamable("https://streamable.com/"), None);
+        assert_eq!(
+            detect_streamable("https://streamable.com/login/reset"),
+            None
+        );
+    }
+
+    #[test]
+    fn vimeo() {
+        assert_eq!(
+            detect_vimeo("https://vimeo.com/76979871"),
+            Some(Special::Vimeo {
+                id: "76979871".to_string()
+            })
+        );
+        assert_eq!(
+            detect_vimeo("https://player.vimeo.com/video/76979871"),
+            Some(Special::Vimeo {
+                id: "76979871".to_string()
+            })
+        );
+        assert_eq!(
+            detect_vimeo("https://vimeo.com/channels/staffpicks/76979871"),
+            Some(Special::Vimeo {
+                id: "76979871".to_string()
+            })
+        );
+
+        assert_eq!(detect_vimeo("https://vimeo.com/someuser"), None);
+        assert_eq!(detect_vimeo("https://notvimeo.com/76979871"), None);
+    }
+}
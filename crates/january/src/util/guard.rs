@@ -0,0 +1,110 @@
+use std::{error::Error as StdError, fmt, net::IpAddr};
+
+use chat_core::util::guard::is_forbidden_ip;
+use hyper::client::connect::dns::Name;
+use reqwest::{
+    dns::{Addrs, Resolve, Resolving},
+    redirect::{Attempt, Policy},
+    Url,
+};
+
+use super::variables::{ALLOWED_HOSTS, ALLOWED_PORTS};
+
+/// Maximum number of redirects followed for a single request
+const MAX_REDIRECTS: usize = 10;
+
+/// Request was refused because it targets a forbidden address
+#[derive(Debug)]
+pub struct Blocked;
+
+impl fmt::Display for Blocked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "request to a forbidden address was blocked")
+    }
+}
+
+impl StdError for Blocked {}
+
+/// Check whether an error was caused by the guard
+pub fn is_blocked(error: &(dyn StdError + 'static)) -> bool {
+    let mut error = Some(error);
+    while let Some(current) = error {
+        if current.is::<Blocked>() {
+            return true;
+        }
+
+        error = current.source();
+    }
+
+    false
+}
+
+/// Check whether a host may resolve to any address
+fn is_allowed_host(host: &str) -> bool {
+    ALLOWED_HOSTS
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(host))
+}
+
+/// Check the scheme, port and any literal address of a URL
+///
+/// Host names are checked once resolved, see [`GuardedResolver`].
+/// Allowed hosts may use any port.
+pub fn check_url(url: &Url) -> Result<(), Blocked> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(Blocked);
+    }
+
+    let host = url.host_str().ok_or(Blocked)?;
+    if is_allowed_host(host) {
+        return Ok(());
+    }
+
+    match url.port_or_known_default() {
+        Some(port) if ALLOWED_PORTS.contains(&port) => {}
+        _ => return Err(Blocked),
+    }
+
+    // Literal addresses never go through the resolver.
+    match host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        Ok(ip) if is_forbidden_ip(ip) => Err(Blocked),
+        _ => Ok(()),
+    }
+}
+
+/// Redirect policy which checks every hop
+pub fn redirect_policy() -> Policy {
+    Policy::custom(|attempt: Attempt| {
+        if attempt.previous().len() >= MAX_REDIRECTS {
+            attempt.error("too many redirects")
+        } else if check_url(attempt.url()).is_err() {
+            attempt.error(Blocked)
+        } else {
+            attempt.follow()
+        }
+    })
+}
+
+/// DNS resolver which refuses names resolving to non-public addresses
+pub struct GuardedResolver;
+
+impl Resolve for GuardedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<_> = tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+
+            // Refuse the whole name rather than picking out public addresses,
+            // so a name cannot flip between public and private results.
+            if !is_allowed_host(&host) && addrs.iter().any(|addr| is_forbidden_ip(addr.ip())) {
+                return Err(Box::new(Blocked) as Box<dyn StdError + Send + Sync>);
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
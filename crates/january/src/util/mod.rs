@@ -1,3 +1,5 @@
+pub mod guard;
 pub mod request;
+pub mod resize;
 pub mod result;
 pub mod variables;
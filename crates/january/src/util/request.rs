@@ -1,14 +1,18 @@
-use super::{result::Error, variables::MAX_BYTES};
+use super::{
+    guard::{check_url, is_blocked, redirect_policy, GuardedResolver},
+    result::Error,
+    variables::MAX_BYTES,
+};
 use actix_web::web::Bytes;
 use encoding_rs::{Encoding, UTF_8_INIT};
 use ffprobe::ffprobe;
 use mime::Mime;
 use reqwest::{
     header::{self, CONTENT_TYPE},
-    Client, Response,
+    Client, Response, Url,
 };
 use scraper::Html;
-use std::{io::Write, time::Duration};
+use std::{io::Write, sync::Arc, time::Duration};
 use tempfile::NamedTempFile;
 
 lazy_static! {
@@ -16,18 +20,27 @@ lazy_static! {
         .user_agent("Mozilla/5.0")
         .timeout(Duration::from_secs(15))
         .connect_timeout(Duration::from_secs(5))
+        .redirect(redirect_policy())
+        .dns_resolver(Arc::new(GuardedResolver))
+        // A proxy would resolve names itself, bypassing the guard.
+        .no_proxy()
         .build()
         .expect("reqwest Client");
 }
 
 pub async fn fetch(url: &str) -> Result<(Response, Mime), Error> {
-    let resp = CLIENT
-        .get(url)
-        .send()
-        .await
-        .map_err(|_| Error::ReqwestFailed)?;
+    let url = Url::parse(url).map_err(|_| Error::RequestFailed)?;
+    check_url(&url).map_err(|_| Error::ForbiddenAddress)?;
+
+    let resp = CLIENT.get(url).send().await.map_err(|err| {
+        if is_blocked(&err) {
+            Error::ForbiddenAddress
+        } else {
+            Error::ReqwestFailed
+        }
+    })?;
 
-    if resp.status().is_success() {
+    if !resp.status().is_success() {
         return Err(Error::RequestFailed);
     }
 
@@ -0,0 +1,131 @@
+use std::io::Cursor;
+
+use image::{
+    codecs::{gif::GifDecoder, jpeg::JpegEncoder},
+    imageops::FilterType,
+    AnimationDecoder, ImageDecoder, ImageFormat, ImageReader, Limits,
+};
+use serde::Deserialize;
+
+use super::{result::Error, variables::MAX_SOURCE_DIMENSION};
+
+/// Output format for processed images
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Webp,
+    Jpeg,
+}
+
+impl OutputFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            OutputFormat::Webp => "image/webp",
+            OutputFormat::Jpeg => "image/jpeg",
+        }
+    }
+}
+
+/// Quality used when encoding lossy output
+const QUALITY: u8 = 80;
+
+/// Result of processing an image
+pub enum Processed {
+    /// Image was re-encoded with the given content type
+    Image(Vec<u8>, &'static str),
+    /// Source should be served unchanged, for example an animated GIF
+    Original,
+}
+
+/// Find the size an image should be scaled down to so it fits within the given bounds
+///
+/// Aspect ratio is preserved and images are never scaled up.
+pub fn fit_within(
+    width: u32,
+    height: u32,
+    max_width: Option<u32>,
+    max_height: Option<u32>,
+) -> (u32, u32) {
+    let scale_width = max_width.map(|max| max as f64 / width as f64);
+    let scale_height = max_height.map(|max| max as f64 / height as f64);
+
+    let scale = match (scale_width, scale_height) {
+        (Some(a), Some(b)) => a.min(b),
+        (Some(a), None) | (None, Some(a)) => a,
+        (None, None) => 1.0,
+    };
+
+    if scale >= 1.0 {
+        (width, height)
+    } else {
+        (
+            ((width as f64 * scale).round() as u32).max(1),
+            ((height as f64 * scale).round() as u32).max(1),
+        )
+    }
+}
+
+/// Check whether a GIF has more than one frame
+fn is_animated_gif(bytes: &[u8], limits: Limits) -> bool {
+    match GifDecoder::new(Cursor::new(bytes)) {
+        Ok(mut decoder) => {
+            decoder.set_limits(limits).is_ok() && decoder.into_frames().take(2).count() > 1
+        }
+        Err(_) => false,
+    }
+}
+
+/// Resize and re-encode an image
+///
+/// Anything which is not a still image is left untouched.
+pub fn process(
+    bytes: &[u8],
+    max_width: Option<u32>,
+    max_height: Option<u32>,
+    format: OutputFormat,
+) -> Result<Processed, Error> {
+    let mut reader = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|_| Error::ConversionFailed)?;
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(*MAX_SOURCE_DIMENSION);
+    limits.max_image_height = Some(*MAX_SOURCE_DIMENSION);
+
+    match reader.format() {
+        Some(ImageFormat::Gif) if is_animated_gif(bytes, limits.clone()) => {
+            return Ok(Processed::Original)
+        }
+        Some(ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::Gif | ImageFormat::WebP) => {}
+        _ => return Ok(Processed::Original),
+    }
+
+    reader.limits(limits);
+
+    let image = reader.decode().map_err(|_| Error::ConversionFailed)?;
+
+    let (width, height) = fit_within(image.width(), image.height(), max_width, max_height);
+    let image = if (width, height) == (image.width(), image.height()) {
+        image
+    } else {
+        image.resize_exact(width, height, FilterType::CatmullRom)
+    };
+
+    let data = match format {
+        OutputFormat::Webp => {
+            let image = image.to_rgba8();
+            webp::Encoder::from_rgba(&image, width, height)
+                .encode(QUALITY as f32)
+                .to_vec()
+        }
+        OutputFormat::Jpeg => {
+            let mut data = Vec::new();
+            JpegEncoder::new_with_quality(&mut data, QUALITY)
+                .encode_image(&image.to_rgb8())
+                .map_err(|_| Error::ConversionFailed)?;
+            data
+        }
+    };
+
+    Ok(Processed::Image(data, format.content_type()))
+}
@@ -16,11 +16,14 @@ pub enum Error {
     MetaSelectionFailed,
     MissingContentType,
     NotAllowedToProxy,
+    ForbiddenAddress,
     ConversionFailed,
+    InvalidDimensions,
     ExceedsMaxBytes,
     ReqwestFailed,
     RequestFailed,
     ProbeError,
+    NoOEmbedEndpoint,
     LabelMe,
     FailedValidation {
         #[serde(skip_serializing, skip_deserializing)]
@@ -45,11 +48,14 @@ impl ResponseError for Error {
             Error::MetaSelectionFailed => StatusCode::INTERNAL_SERVER_ERROR,
             Error::MissingContentType => StatusCode::BAD_REQUEST,
             Error::NotAllowedToProxy => StatusCode::BAD_REQUEST,
+            Error::ForbiddenAddress => StatusCode::FORBIDDEN,
             Error::ConversionFailed => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::InvalidDimensions => StatusCode::BAD_REQUEST,
             Error::ExceedsMaxBytes => StatusCode::BAD_REQUEST,
             Error::ReqwestFailed => StatusCode::INTERNAL_SERVER_ERROR,
             Error::RequestFailed => StatusCode::BAD_REQUEST,
             Error::ProbeError => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::NoOEmbedEndpoint => StatusCode::NOT_FOUND,
             Error::LabelMe => StatusCode::INTERNAL_SERVER_ERROR,
             Error::FailedValidation { .. } => StatusCode::BAD_REQUEST,
         }
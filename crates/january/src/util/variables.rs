@@ -6,4 +6,14 @@ lazy_static! {
         env::var("JANUARY_PUBLIC_URL").expect("Missing JANUARY_PUBLIC_URL environment variable.");
     pub static ref MAX_BYTES: usize =
         env::var("JANUARY_MAX_BYTES").unwrap_or("104857600".to_string()).parse().expect("Invalid JANUARY_MAX_BYTES environment variable.");
+    pub static ref MAX_RESIZE_DIMENSION: u32 =
+        env::var("JANUARY_MAX_RESIZE_DIMENSION").unwrap_or("2048".to_string()).parse().expect("Invalid JANUARY_MAX_RESIZE_DIMENSION environment variable.");
+    pub static ref MAX_SOURCE_DIMENSION: u32 =
+        env::var("JANUARY_MAX_SOURCE_DIMENSION").unwrap_or("16384".to_string()).parse().expect("Invalid JANUARY_MAX_SOURCE_DIMENSION environment variable.");
+    pub static ref ALLOWED_HOSTS: Vec<String> =
+        env::var("JANUARY_ALLOWED_HOSTS").unwrap_or_default().split(',').map(|host| host.trim().to_string()).filter(|host| !host.is_empty()).collect();
+    pub static ref ALLOWED_PORTS: Vec<u16> =
+        env::var("JANUARY_ALLOWED_PORTS").unwrap_or("80,443,8080,8443".to_string()).split(',').map(|port| port.trim().parse().expect("Invalid JANUARY_ALLOWED_PORTS environment variable.")).collect();
+    pub static ref OEMBED_PROVIDERS: Option<String> =
+        env::var("JANUARY_OEMBED_PROVIDERS").ok();
 }
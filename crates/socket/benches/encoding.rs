@@ -0,0 +1,130 @@
+//! Compare message sizes and encode times for every protocol format and compression
+//!
+//! Run with `cargo bench -p chat-socket`.
+
+use std::time::{Duration, Instant};
+
+use chat_socket::config::{ProtocolCompression, ProtocolConfiguration, ProtocolFormat};
+use serde_json::{json, Value};
+
+/// Ready payload shaped like one for a user in many busy servers
+fn ready_payload(servers: usize, users: usize) -> Value {
+    json!({
+        "type": "Ready",
+        "users": (0..users).map(|i| json!({
+            "_id": format!("01FD58YK5W7QRV5H3D64KTQ{i:03}"),
+            "username": format!("user{i}"),
+            "discriminator": format!("{:04}", i % 10_000),
+            "avatar": {
+                "_id": format!("avatar-{i}"),
+                "tag": "avatars",
+                "filename": "avatar.png",
+                "metadata": { "type": "Image", "width": 256, "height": 256 },
+                "content_type": "image/png",
+                "size": 48_213
+            },
+            "relationship": "None",
+            "online": i % 3 == 0
+        })).collect::<Vec<Value>>(),
+        "servers": (0..servers).map(|i| json!({
+            "_id": format!("01FD58YK5W7QRV5H3D64KSE{i:03}"),
+            "owner": "01FD58YK5W7QRV5H3D64KTQ000",
+            "name": format!("Server {i}"),
+            "channels": (0..20).map(|c| format!("01FD58YK5W7QRV5H3D64KC{i:02}{c:02}")).collect::<Vec<String>>(),
+            "default_permissions": 4_000_542_720_u64,
+            "roles": {
+                "01FD58YK5W7QRV5H3D64KR0001": { "name": "Moderator", "permissions": { "a": 0, "d": 0 }, "rank": 1 }
+            }
+        })).collect::<Vec<Value>>(),
+        "channels": (0..servers * 20).map(|c| json!({
+            "channel_type": "TextChannel",
+            "_id": format!("01FD58YK5W7QRV5H3D64KC{c:04}"),
+            "server": format!("01FD58YK5W7QRV5H3D64KSE{:03}", c / 20),
+            "name": format!("channel-{c}"),
+            "last_message_id": "01FD58YK5W7QRV5H3D64KM0000"
+        })).collect::<Vec<Value>>(),
+        "members": (0..servers).map(|i| json!({
+            "_id": {
+                "server": format!("01FD58YK5W7QRV5H3D64KSE{i:03}"),
+                "user": "01FD58YK5W7QRV5H3D64KTQ000"
+            },
+            "joined_at": "2022-02-14T12:00:00.000Z"
+        })).collect::<Vec<Value>>(),
+        "emojis": []
+    })
+}
+
+/// Small message event, as seen in the steady state of a connection
+fn message_event(i: usize) -> Value {
+    json!({
+        "type": "Message",
+        "_id": format!("01FD58YK5W7QRV5H3D64KM{i:04}"),
+        "channel": "01FD58YK5W7QRV5H3D64KC0001",
+        "author": format!("01FD58YK5W7QRV5H3D64KTQ{:03}", i % 50),
+        "content": format!("Hello there, this is message number {i} in the channel."),
+        "seq": i
+    })
+}
+
+fn format_name(format: ProtocolFormat) -> &'static str {
+    match format {
+        ProtocolFormat::Json => "json",
+        ProtocolFormat::Msgpack => "msgpack",
+    }
+}
+
+fn compression_name(compression: ProtocolCompression) -> &'static str {
+    match compression {
+        ProtocolCompression::None => "none",
+        ProtocolCompression::ZlibStream => "zlib-stream",
+        ProtocolCompression::ZstdStream => "zstd-stream",
+    }
+}
+
+fn main() {
+    let ready = ready_payload(100, 2_000);
+    let messages: Vec<Value> = (0..1_000).map(message_event).collect();
+
+    println!(
+        "{:<8} {:<12} {:>12} {:>12} {:>16} {:>12}",
+        "format", "compression", "ready bytes", "ready time", "1000 msg bytes", "msg time"
+    );
+
+    for format in [ProtocolFormat::Json, ProtocolFormat::Msgpack] {
+        for compression in [
+            ProtocolCompression::None,
+            ProtocolCompression::ZlibStream,
+            ProtocolCompression::ZstdStream,
+        ] {
+            let name = format_name(format);
+
+            // Each connection starts with a fresh compression context.
+            let config = ProtocolConfiguration::from(1, format, compression, None);
+
+            let start = Instant::now();
+            let ready_bytes = config.encode(&ready).len();
+            let ready_time = start.elapsed();
+
+            let start = Instant::now();
+            let message_bytes: usize = messages
+                .iter()
+                .map(|message| config.encode(message).len())
+                .sum();
+            let message_time = start.elapsed();
+
+            println!(
+                "{:<8} {:<12} {:>12} {:>12} {:>16} {:>12}",
+                name,
+                compression_name(compression),
+                ready_bytes,
+                millis(ready_time),
+                message_bytes,
+                millis(message_time)
+            );
+        }
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
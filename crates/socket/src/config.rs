@@ -1,18 +1,88 @@
+use std::{fmt, io::Write, sync::Mutex};
+
 use async_tungstenite::tungstenite::{handshake, Message};
 use chat_core::{Error, Result};
+use flate2::{write::ZlibEncoder, Compression};
 use futures::channel::oneshot::Sender;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum ProtocolFormat {
     Json,
     Msgpack,
 }
 
+/// Compression applied to messages sent to the client
+///
+/// Messages from the client are never compressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtocolCompression {
+    None,
+    /// One zlib stream for the whole connection, every message ends in a sync flush
+    ZlibStream,
+    /// One zstd stream for the whole connection, every message ends in a flushed block
+    ZstdStream,
+}
+
+/// Compression context shared by every message sent on a connection
+pub enum Compressor {
+    Zlib(ZlibEncoder<Vec<u8>>),
+    Zstd(zstd::stream::write::Encoder<'static, Vec<u8>>),
+}
+
+impl Compressor {
+    pub fn new(compression: ProtocolCompression) -> Option<Self> {
+        match compression {
+            ProtocolCompression::None => None,
+            ProtocolCompression::ZlibStream => Some(Compressor::Zlib(ZlibEncoder::new(
+                Vec::new(),
+                Compression::default(),
+            ))),
+            ProtocolCompression::ZstdStream => zstd::stream::write::Encoder::new(Vec::new(), 3)
+                .ok()
+                .map(Compressor::Zstd),
+        }
+    }
+
+    /// Compress the next message in the stream
+    ///
+    /// The output only decodes correctly if every earlier message
+    /// was passed to the client's decompressor in order.
+    pub fn compress(&mut self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Compressor::Zlib(encoder) => {
+                encoder
+                    .write_all(data)
+                    .expect("Failed to compress (as zlib).");
+                encoder.flush().expect("Failed to flush (as zlib).");
+                std::mem::take(encoder.get_mut())
+            }
+            Compressor::Zstd(encoder) => {
+                encoder
+                    .write_all(data)
+                    .expect("Failed to compress (as zstd).");
+                encoder.flush().expect("Failed to flush (as zstd).");
+                std::mem::take(encoder.get_mut())
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Compressor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Compressor::Zlib(_) => write!(f, "Compressor::Zlib"),
+            Compressor::Zstd(_) => write!(f, "Compressor::Zstd"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ProtocolConfiguration {
     protocol_version: i32,
     format: ProtocolFormat,
+    compression: ProtocolCompression,
+    compressor: Option<Mutex<Compressor>>,
     session_token: Option<String>,
 }
 
@@ -20,11 +90,14 @@ impl ProtocolConfiguration {
     pub fn from(
         protocol_version: i32,
         format: ProtocolFormat,
+        compression: ProtocolCompression,
         session_token: Option<String>,
     ) -> Self {
         Self {
             protocol_version,
             format,
+            compression,
+            compressor: Compressor::new(compression).map(Mutex::new),
             session_token,
         }
     }
@@ -48,14 +121,29 @@ impl ProtocolConfiguration {
         }
     }
 
+    /// Encode a message for the client
+    ///
+    /// With stream compression, messages must be sent in the order they
+    /// were encoded, so encode while holding the write lock.
     pub fn encode<T: Serialize>(&self, data: &T) -> Message {
-        match self.format {
+        let message = match self.format {
             ProtocolFormat::Json => {
                 Message::Text(serde_json::to_string(data).expect("Failed to serialize (as json)."))
             }
             ProtocolFormat::Msgpack => Message::Binary(
                 rmp_serde::to_vec_named(data).expect("Failed to serialize (as msgpack)."),
             ),
+        };
+
+        if let Some(compressor) = &self.compressor {
+            Message::Binary(
+                compressor
+                    .lock()
+                    .expect("Compressor lock poisoned.")
+                    .compress(&message.into_data()),
+            )
+        } else {
+            message
         }
     }
 
@@ -74,6 +162,10 @@ impl ProtocolConfiguration {
     pub fn get_protocol_format(&self) -> &ProtocolFormat {
         &self.format
     }
+
+    pub fn get_protocol_compression(&self) -> ProtocolCompression {
+        self.compression
+    }
 }
 
 pub struct WebsocketHandshakeCallback {
@@ -97,6 +189,7 @@ impl handshake::server::Callback for WebsocketHandshakeCallback {
 
         let mut protocol_version = 1;
         let mut format = ProtocolFormat::Json;
+        let mut compression = ProtocolCompression::None;
         let mut session_token = None;
 
         for (key, value) in params {
@@ -111,6 +204,11 @@ impl handshake::server::Callback for WebsocketHandshakeCallback {
                     "msgpack" => format = ProtocolFormat::Msgpack,
                     _ => {}
                 },
+                "compression" => match value {
+                    "zlib-stream" => compression = ProtocolCompression::ZlibStream,
+                    "zstd-stream" => compression = ProtocolCompression::ZstdStream,
+                    _ => {}
+                },
                 "token" => session_token = Some(value.into()),
                 _ => {}
             }
@@ -118,11 +216,12 @@ impl handshake::server::Callback for WebsocketHandshakeCallback {
 
         if self
             .sender
-            .send(ProtocolConfiguration {
+            .send(ProtocolConfiguration::from(
                 protocol_version,
                 format,
+                compression,
                 session_token,
-            })
+            ))
             .is_ok()
         {
             Ok(response)
@@ -9,6 +9,11 @@ pub async fn connect() {
         .await
         .expect("Failed to connect to the database.");
 
+    set_db(database);
+}
+
+/// Share an existing database connection with the websocket server
+pub fn set_db(database: Database) {
     DB_CONN.set(database).expect("Setting `Database`");
 }
 
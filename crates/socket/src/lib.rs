@@ -0,0 +1,23 @@
+use async_std::net::TcpListener;
+use chat_core::Database;
+
+#[macro_use]
+extern crate log;
+
+pub mod config;
+pub mod database;
+
+mod session;
+mod websocket;
+
+/// Accept websocket connections until the listener fails
+pub async fn listen(db: &'static Database, bind: String) {
+    info!("Listening on host {bind}");
+
+    let try_socket = TcpListener::bind(bind).await;
+    let listener = try_socket.expect("Failed to bind");
+
+    while let Ok((stream, addr)) = listener.accept().await {
+        websocket::spawn_client(db, stream, addr);
+    }
+}
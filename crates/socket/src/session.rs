@@ -0,0 +1,321 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+use async_std::{
+    sync::{Mutex, MutexGuard},
+    task,
+};
+use chat_core::{
+    events::{
+        bus::event_bus,
+        client::EventV1,
+        state::{State, SubscriptionStateChange},
+    },
+    models::User,
+    presence_create_session, presence_delete_session,
+    variables::delta::{MONO_CHAT_WS_REPLAY_BUFFER, MONO_CHAT_WS_RESUME_WINDOW},
+    voice_disconnect, Database, SessionPresence,
+};
+use futures::{channel::mpsc, select, FutureExt, StreamExt};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use ulid::Ulid;
+
+/// Sessions live on this node, keyed by session ID
+static SESSIONS: Lazy<Mutex<HashMap<String, Arc<Session>>>> = Lazy::new(Default::default);
+
+/// Event tagged with its position in the session
+#[derive(Serialize, Clone)]
+pub struct SequencedEvent {
+    #[serde(flatten)]
+    pub event: EventV1,
+    pub seq: u64,
+}
+
+/// Bounded buffer of the most recent events delivered in a session
+#[derive(Default)]
+pub struct ReplayBuffer {
+    seq: u64,
+    events: VecDeque<SequencedEvent>,
+}
+
+impl ReplayBuffer {
+    /// Record an event and assign it the next sequence number
+    pub fn push(&mut self, event: EventV1) -> SequencedEvent {
+        self.seq += 1;
+
+        let event = SequencedEvent {
+            event,
+            seq: self.seq,
+        };
+
+        self.events.push_back(event.clone());
+        while self.events.len() > *MONO_CHAT_WS_REPLAY_BUFFER {
+            self.events.pop_front();
+        }
+
+        event
+    }
+
+    /// Find every event after the given sequence number
+    ///
+    /// Returns None if any of them have already been dropped from the buffer.
+    pub fn since(&self, seq: u64) -> Option<Vec<SequencedEvent>> {
+        if seq > self.seq {
+            return None;
+        }
+
+        let oldest = self
+            .events
+            .front()
+            .map(|event| event.seq)
+            .unwrap_or(self.seq + 1);
+
+        if seq + 1 < oldest {
+            return None;
+        }
+
+        Some(
+            self.events
+                .iter()
+                .filter(|event| event.seq > seq)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Drop all buffered events, sequence numbers keep counting up
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+}
+
+/// Mutable session state, shared between the listener and the attached connection
+pub struct SessionInner {
+    pub state: State,
+    pub buffer: ReplayBuffer,
+    sink: Option<mpsc::UnboundedSender<SequencedEvent>>,
+    generation: u64,
+    closed: bool,
+}
+
+impl SessionInner {
+    /// Attach a new connection, replacing any existing one
+    ///
+    /// Returns the attachment generation and the stream of new events.
+    pub fn attach(&mut self) -> (u64, mpsc::UnboundedReceiver<SequencedEvent>) {
+        let (sender, receiver) = mpsc::unbounded();
+        self.generation += 1;
+        self.sink = Some(sender);
+        (self.generation, receiver)
+    }
+}
+
+/// Resumable event session
+///
+/// A session outlives its connection for a short while so that clients
+/// can reconnect and receive any events they missed in the meantime.
+pub struct Session {
+    pub id: String,
+    pub user_id: String,
+    pub presence_session_id: u8,
+    inner: Mutex<SessionInner>,
+    wake: mpsc::UnboundedSender<()>,
+}
+
+impl Session {
+    /// Create a new session and start listening for events
+    ///
+    /// Also returns whether this is the user's first presence session
+    /// and their new effective presence if it changed.
+    pub async fn create(
+        db: &'static Database,
+        user: User,
+        lazy: bool,
+    ) -> (Arc<Session>, bool, Option<SessionPresence>) {
+        let user_id = user.id.clone();
+        let (first_session, presence_session_id, presence_change) =
+            presence_create_session(&user_id, 0).await;
+
+        let mut state = State::from(user);
+        state.lazy = lazy;
+
+        let (wake, woken) = mpsc::unbounded();
+        let session = Arc::new(Session {
+            id: Ulid::new().to_string(),
+            user_id,
+            presence_session_id,
+            inner: Mutex::new(SessionInner {
+                state,
+                buffer: Default::default(),
+                sink: None,
+                generation: 0,
+                closed: false,
+            }),
+            wake,
+        });
+
+        SESSIONS
+            .lock()
+            .await
+            .insert(session.id.clone(), session.clone());
+
+        task::spawn(session.clone().listen(db, woken));
+        (session, first_session, presence_change)
+    }
+
+    /// Find an open session on this node
+    pub async fn find(id: &str) -> Option<Arc<Session>> {
+        let session = SESSIONS.lock().await.get(id).cloned()?;
+        if session.inner.lock().await.closed {
+            None
+        } else {
+            Some(session)
+        }
+    }
+
+    /// Lock the session state
+    pub async fn lock(&self) -> MutexGuard<'_, SessionInner> {
+        self.inner.lock().await
+    }
+
+    /// Wake the listener so it picks up subscription changes
+    pub fn wake(&self) {
+        self.wake.unbounded_send(()).ok();
+    }
+
+    /// Detach a connection from this session
+    ///
+    /// A resumable session is closed if nothing resumes it within the
+    /// resume window, otherwise it is closed straight away.
+    pub async fn detach(self: &Arc<Self>, generation: u64, resumable: bool) {
+        {
+            let mut inner = self.inner.lock().await;
+            if inner.generation != generation || inner.closed {
+                return;
+            }
+
+            inner.sink = None;
+        }
+
+        if !resumable {
+            self.close().await;
+            return;
+        }
+
+        let session = self.clone();
+        task::spawn(async move {
+            task::sleep(Duration::from_secs(*MONO_CHAT_WS_RESUME_WINDOW)).await;
+
+            {
+                let inner = session.inner.lock().await;
+                if inner.generation != generation || inner.sink.is_some() {
+                    return;
+                }
+            }
+
+            session.close().await;
+        });
+    }
+
+    /// Close this session, stop listening and end its presence session
+    pub async fn close(&self) {
+        {
+            let mut inner = self.inner.lock().await;
+            if inner.closed {
+                return;
+            }
+
+            inner.closed = true;
+            inner.sink = None;
+        }
+
+        self.wake();
+        SESSIONS.lock().await.remove(&self.id);
+
+        let (last_session, presence_change) =
+            presence_delete_session(&self.user_id, self.presence_session_id).await;
+
+        let inner = self.inner.lock().await;
+        if last_session {
+            // Nothing is left to keep the user in a call.
+            voice_disconnect(&self.user_id).await;
+            inner.state.broadcast_presence_change(false, None).await;
+        } else if presence_change.is_some() {
+            inner
+                .state
+                .broadcast_presence_change(true, presence_change)
+                .await;
+        }
+    }
+
+    /// Listen for events on every subscribed topic and record them in the session
+    async fn listen(
+        self: Arc<Self>,
+        db: &'static Database,
+        mut woken: mpsc::UnboundedReceiver<()>,
+    ) {
+        if let Ok(mut subscription) = event_bus().open_subscription().await {
+            loop {
+                {
+                    let mut inner = self.inner.lock().await;
+                    if inner.closed {
+                        break;
+                    }
+
+                    match inner.state.apply_state() {
+                        SubscriptionStateChange::Reset => {
+                            for id in inner.state.iter_subscriptions() {
+                                subscription.subscribe(id).await;
+                            }
+
+                            #[cfg(debug_assertions)]
+                            info!("Session {} has reset their subscriptions", self.id);
+                        }
+                        SubscriptionStateChange::Change { add, remove } => {
+                            for id in remove {
+                                #[cfg(debug_assertions)]
+                                info!("Session {} unsubscribing from {id}", self.id);
+
+                                subscription.unsubscribe(id).await;
+                            }
+
+                            for id in add {
+                                #[cfg(debug_assertions)]
+                                info!("Session {} subscribing to {id}", self.id);
+
+                                subscription.subscribe(id).await;
+                            }
+                        }
+                        SubscriptionStateChange::None => {}
+                    }
+                }
+
+                let event = select! {
+                    _ = woken.next() => continue,
+                    event = subscription.next().fuse() => event,
+                };
+
+                match event {
+                    Some(mut event) => {
+                        let mut inner = self.inner.lock().await;
+                        if inner.state.handle_incoming_event_v1(db, &mut event).await {
+                            let event = inner.buffer.push(event);
+                            if let Some(sink) = &inner.sink {
+                                if sink.unbounded_send(event).is_err() {
+                                    inner.sink = None;
+                                }
+                            }
+                        }
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        self.close().await;
+    }
+}
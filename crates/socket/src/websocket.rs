@@ -1,20 +1,26 @@
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
 
+use async_tungstenite::tungstenite::Message;
+use chat_core::presence_set_session;
 use chat_core::{
-    events::{
-        client::EventV1,
-        server::ClientMessage,
-        state::{State, SubscriptionStateChange},
+    events::{client::EventV1, server::ClientMessage},
+    models::{
+        ratelimit_events::ratelimit::{RatelimitEvent, RatelimitEventType},
+        user::{UserActivity, UserHint},
+        User,
     },
-    models::{user::UserHint, User},
-    redis_kiss, Database,
+    variables::delta::{MONO_CHAT_WS_HEARTBEAT_INTERVAL, MONO_CHAT_WS_HEARTBEAT_TIMEOUT},
+    Database, Result,
 };
-use chat_core::{presence_create_session, presence_delete_session};
 use futures::{channel::oneshot, pin_mut, select, FutureExt, SinkExt, StreamExt, TryStreamExt};
 
 use async_std::{net::TcpStream, sync::Mutex, task};
 
 use crate::config::WebsocketHandshakeCallback;
+use crate::session::Session;
 
 pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr) {
     task::spawn(async move {
@@ -29,21 +35,34 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
         {
             if let Ok(mut config) = receiver.await {
                 info!(
-                    "User {addr:?} provided protocol configuration (version = {}, format = {:?})",
+                    "User {addr:?} provided protocol configuration (version = {}, format = {:?}, compression = {:?})",
                     config.get_protocol_version(),
-                    config.get_protocol_format()
+                    config.get_protocol_format(),
+                    config.get_protocol_compression()
                 );
 
                 let (write, mut read) = ws.split();
                 let write = Mutex::new(write);
 
+                // Resume must be sent as the first message, in place of Authenticate.
+                let mut resume = None;
                 if config.get_session_token().is_none() {
                     'outer: while let Ok(message) = read.try_next().await {
-                        if let Ok(ClientMessage::Authenticate { token }) =
-                            config.decode(message.as_ref().unwrap())
-                        {
-                            config.set_session_token(token);
-                            break 'outer;
+                        match config.decode(message.as_ref().unwrap()) {
+                            Ok(ClientMessage::Authenticate { token }) => {
+                                config.set_session_token(token);
+                                break 'outer;
+                            }
+                            Ok(ClientMessage::Resume {
+                                token,
+                                session_id,
+                                seq,
+                            }) => {
+                                config.set_session_token(token);
+                                resume = Some((session_id, seq));
+                                break 'outer;
+                            }
+                            _ => {}
                         }
                     }
                 }
@@ -53,152 +72,310 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
                         Ok(user) => {
                             info!("User {addr:?} authenticated as @{}", user.username);
 
-                            let mut state = State::from(user);
-                            let user_id = state.cache.user_id.clone();
-                            let (first_session, session_id) = presence_create_session(&user_id, 0).await;
+                            // Only the owner may resume a session that is still open on this node.
+                            let resumable = match resume {
+                                Some((session_id, seq)) => match Session::find(&session_id).await {
+                                    Some(session) if session.user_id == user.id => {
+                                        Some((session, seq))
+                                    }
+                                    _ => None,
+                                },
+                                None => None,
+                            };
+
+                            let (session, resume_seq, presence_broadcast) = match resumable {
+                                Some((session, seq)) => (session, Some(seq), None),
+                                None => {
+                                    let (session, first_session, presence_change) =
+                                        Session::create(
+                                            db,
+                                            user,
+                                            config.get_protocol_version() >= 2,
+                                        )
+                                        .await;
+
+                                    (
+                                        session,
+                                        None,
+                                        if first_session || presence_change.is_some() {
+                                            Some(presence_change)
+                                        } else {
+                                            None
+                                        },
+                                    )
+                                }
+                            };
+
+                            let user_id = session.user_id.clone();
+
+                            write
+                                .lock()
+                                .await
+                                .send(config.encode(&EventV1::Authenticated {
+                                    heartbeat_interval: *MONO_CHAT_WS_HEARTBEAT_INTERVAL,
+                                }))
+                                .await
+                                .ok();
 
                             write
                                 .lock()
                                 .await
-                                .send(config.encode(&EventV1::Authenticated))
+                                .send(config.encode(&EventV1::Session {
+                                    id: session.id.clone(),
+                                }))
                                 .await
                                 .ok();
-                            if let Ok(ready_payload) = state.generate_ready_payload(db).await {
-                                write
-                                    .lock()
-                                    .await
-                                    .send(config.encode(&ready_payload))
-                                    .await
-                                    .ok();
-
-                                    if first_session {
-                                        state.broadcast_presence_change(true).await;
+
+                            // Attach while holding the lock so no event falls between
+                            // the replay (or Ready) and the live stream.
+                            let (generation, mut events, replay, ready) = {
+                                let mut inner = session.lock().await;
+                                let (generation, events) = inner.attach();
+
+                                match resume_seq.and_then(|seq| inner.buffer.since(seq)) {
+                                    Some(replay) => (generation, events, Some(replay), None),
+                                    None => {
+                                        inner.buffer.clear();
+                                        let ready = inner.state.generate_ready_payload(db).await;
+                                        (generation, events, None, Some(ready))
                                     }
+                                }
+                            };
+
+                            session.wake();
 
-                                    let listener = async {
-                                        if let Ok(mut conn) = redis_kiss::open_pubsub_connection().await{
-                                            loop{
-                                                match state.apply_state() {
-                                                    SubscriptionStateChange::Reset => {
-                                                        for id in state.iter_subscriptions() {
-                                                            conn.subscribe(id).await.unwrap();
-                                                        }
-    
-                                                        #[cfg(debug_assertions)]
-                                                        info!("{addr:?} has reset their subscriptions");
+                            let ready = match (replay, ready) {
+                                (Some(replay), _) => {
+                                    let replayed = replay.len();
+                                    for event in replay {
+                                        write.lock().await.send(config.encode(&event)).await.ok();
+                                    }
+
+                                    write
+                                        .lock()
+                                        .await
+                                        .send(config.encode(&EventV1::Resumed { replayed }))
+                                        .await
+                                        .ok();
+
+                                    true
+                                }
+                                (None, Some(Ok(ready_payload))) => {
+                                    write
+                                        .lock()
+                                        .await
+                                        .send(config.encode(&ready_payload))
+                                        .await
+                                        .ok();
+
+                                    true
+                                }
+                                _ => false,
+                            };
+
+                            let mut timed_out = false;
+                            if ready {
+                                if let Some(presence_change) = presence_broadcast {
+                                    session
+                                        .lock()
+                                        .await
+                                        .state
+                                        .broadcast_presence_change(true, presence_change)
+                                        .await;
+                                }
+
+                                let forwarder = async {
+                                    while let Some(event) = events.next().await {
+                                        if write
+                                            .lock()
+                                            .await
+                                            .send(config.encode(&event))
+                                            .await
+                                            .is_err()
+                                        {
+                                            break;
+                                        }
+                                    }
+                                }
+                                .fuse();
+
+                                // Any message from the client, including pong frames, counts as a heartbeat.
+                                let last_seen = Mutex::new(Instant::now());
+
+                                let heartbeat = async {
+                                    let interval =
+                                        Duration::from_secs(*MONO_CHAT_WS_HEARTBEAT_INTERVAL);
+                                    let timeout =
+                                        Duration::from_secs(*MONO_CHAT_WS_HEARTBEAT_TIMEOUT);
+
+                                    loop {
+                                        task::sleep(interval).await;
+
+                                        let idle = last_seen.lock().await.elapsed();
+                                        if idle >= timeout {
+                                            info!("User {addr:?} timed out after {idle:?}");
+                                            return true;
+                                        }
+
+                                        // Clients which never ping on their own still answer ping frames.
+                                        if idle >= interval
+                                            && write
+                                                .lock()
+                                                .await
+                                                .send(Message::Ping(vec![]))
+                                                .await
+                                                .is_err()
+                                        {
+                                            return false;
+                                        }
+                                    }
+                                }
+                                .fuse();
+
+                                let worker = async {
+                                    while let Ok(Some(msg)) = read.try_next().await {
+                                        *last_seen.lock().await = Instant::now();
+
+                                        if let Ok(payload) = config.decode(&msg) {
+                                            match payload {
+                                                ClientMessage::BeginTyping { channel } => {
+                                                    EventV1::ChannelStartTyping {
+                                                        id: channel.clone(),
+                                                        user: user_id.clone(),
                                                     }
-                                                    SubscriptionStateChange::Change { add, remove } => {
-                                                        for id in remove {
-                                                            #[cfg(debug_assertions)]
-                                                            info!("{addr:?} unsubscribing from {id}");
-    
-                                                            conn.unsubscribe(id).await.unwrap();
-                                                        }
-    
-                                                        for id in add {
-                                                            #[cfg(debug_assertions)]
-                                                            info!("{addr:?} subscribing to {id}");
-    
-                                                            conn.subscribe(id).await.unwrap();
-                                                        }
+                                                    .p(channel.clone())
+                                                    .await;
+                                                }
+                                                ClientMessage::EndTyping { channel } => {
+                                                    EventV1::ChannelStopTyping {
+                                                        id: channel.clone(),
+                                                        user: user_id.clone(),
                                                     }
-                                                    SubscriptionStateChange::None => {}
+                                                    .p(channel.clone())
+                                                    .await;
                                                 }
-
-                                                match conn.on_message().next().await.map(|res| {
-                                                    res.map(|item|(
-                                                        item.get_channel_name().to_string(),
-                                                        redis_kiss::decode_payload::<EventV1>(&item),
-                                                    ))
-                                                }) {
-                                                    Some(Ok((channel, item))) => {
-                                                        if let Ok(mut event) = item {
-                                                            if state
-                                                                .handle_incoming_event_v1(
-                                                                    db, &mut event,
-                                                                )
-                                                                .await
-                                                                && write.lock().await
-                                                                    .send(config.encode(&event))
-                                                                    .await
-                                                                    .is_err()
-                                                            {
-                                                                break;
-                                                            }
-                                                        } else {
-                                                            warn!("Failed to deserialize an event for {channel}!");
-                                                        }
+                                                ClientMessage::Ping { data, responded } => {
+                                                    if responded.is_none() {
+                                                        write
+                                                            .lock()
+                                                            .await
+                                                            .send(
+                                                                config.encode(&EventV1::Pong {
+                                                                    data,
+                                                                }),
+                                                            )
+                                                            .await
+                                                            .ok();
                                                     }
-                                                    Some(Err(e)) => {
-                                                        info!("Error while consuming pub/sub messages: {e:?}");
-                                                        sentry::capture_error(&e);
-                                                        break
+                                                }
+                                                ClientMessage::SetActivity { activity } => {
+                                                    if let Err(err) =
+                                                        set_activity(db, &user_id, activity).await
+                                                    {
+                                                        write
+                                                            .lock()
+                                                            .await
+                                                            .send(config.encode(&err))
+                                                            .await
+                                                            .ok();
                                                     }
-                                                    None => break,
                                                 }
-                                        }
-                                    }
-                            }.fuse();
-
-                            let worker =
-                                    async {
-                                        while let Ok(Some(msg)) = read.try_next().await {
-                                            if let Ok(payload) = config.decode(&msg) {
-                                                match payload {
-                                                    ClientMessage::BeginTyping { channel } => {
-                                                        EventV1::ChannelStartTyping {
-                                                            id: channel.clone(),
-                                                            user: user_id.clone(),
-                                                        }
-                                                        .p(channel.clone())
-                                                        .await;
+                                                ClientMessage::SetPresence { presence } => {
+                                                    if let Some(presence) = presence_set_session(
+                                                        &user_id,
+                                                        session.presence_session_id,
+                                                        presence,
+                                                    )
+                                                    .await
+                                                    {
+                                                        session
+                                                            .lock()
+                                                            .await
+                                                            .state
+                                                            .broadcast_presence_change(
+                                                                true,
+                                                                Some(presence),
+                                                            )
+                                                            .await;
                                                     }
-                                                    ClientMessage::EndTyping { channel } => {
-                                                        EventV1::ChannelStopTyping {
-                                                            id: channel.clone(),
-                                                            user: user_id.clone(),
-                                                        }
-                                                        .p(channel.clone())
+                                                }
+                                                ClientMessage::Subscribe { id } => {
+                                                    let result = session
+                                                        .lock()
+                                                        .await
+                                                        .state
+                                                        .subscribe_member_list(db, &id)
                                                         .await;
-                                                    }
-                                                    ClientMessage::Ping { data, responded } => {
-                                                        if responded.is_none() {
-                                                            write
-                                                                .lock()
-                                                                .await
-                                                                .send(config.encode(
-                                                                    &EventV1::Pong { data },
-                                                                ))
-                                                                .await
-                                                                .ok();
-                                                        }
-                                                    }
-                                                    _ => {}
+
+                                                    session.wake();
+
+                                                    match result {
+                                                        Ok(event) => write
+                                                            .lock()
+                                                            .await
+                                                            .send(config.encode(&event))
+                                                            .await
+                                                            .ok(),
+                                                        Err(err) => write
+                                                            .lock()
+                                                            .await
+                                                            .send(config.encode(&err))
+                                                            .await
+                                                            .ok(),
+                                                    };
                                                 }
+                                                ClientMessage::Unsubscribe { id } => {
+                                                    session
+                                                        .lock()
+                                                        .await
+                                                        .state
+                                                        .unsubscribe_member_list(&id);
+
+                                                    session.wake();
+                                                }
+                                                _ => {}
                                             }
                                         }
                                     }
-                                    .fuse();
+                                }
+                                .fuse();
+
+                                pin_mut!(forwarder, worker, heartbeat);
+
+                                timed_out = select!(
+                                    () = forwarder => false,
+                                    () = worker => false,
+                                    timed_out = heartbeat => timed_out
+                                );
+                            }
 
-                            pin_mut!(listener, worker);
+                            // Timed out connections end their session straight away,
+                            // so half-open sockets cannot hold on to presence.
+                            if timed_out {
+                                write.lock().await.close().await.ok();
+                            }
 
-                            select!(
-                                () = listener => {},
-                                () = worker => {}
-                            );
+                            session.detach(generation, !timed_out).await;
                         }
-                        let last_session = presence_delete_session(&user_id, session_id).await;
-                        if last_session {
-                            state.broadcast_presence_change(false).await;
+                        Err(err) => {
+                            write.lock().await.send(config.encode(&err)).await.ok();
                         }
                     }
-                    Err(err) => {
-                        write.lock().await.send(config.encode(&err)).await.ok();
-                    }
                 }
             }
         }
-    }
-    info!("User disconnected from {addr:?}");
+
+        info!("User disconnected from {addr:?}");
     });
 }
+
+/// Set a user's activity, limited by the `ActivityChange` cooldown
+async fn set_activity(db: &Database, user_id: &str, activity: Option<UserActivity>) -> Result<()> {
+    RatelimitEvent::throw_if_on_cooldown(db, user_id, RatelimitEventType::ActivityChange).await?;
+    RatelimitEvent::create(db, user_id.to_string(), RatelimitEventType::ActivityChange).await?;
+
+    db.fetch_user(user_id)
+        .await?
+        .set_activity(db, activity)
+        .await
+}
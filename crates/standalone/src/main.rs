@@ -0,0 +1,59 @@
+use std::{env, thread};
+
+use chat_core::{presence_clear_region, DatabaseInfo};
+
+#[macro_use]
+extern crate log;
+
+/// Run the API, websocket server and January in a single process
+///
+/// Every service reads the same environment, and the API and websocket
+/// server share one database connection and one set of task workers.
+/// Events stay in process unless `MONO_CHAT_EVENT_BUS` says otherwise.
+#[rocket::main]
+async fn main() {
+    chat_core::configure!();
+
+    // Only this process subscribes to events, so Redis pub/sub is not needed.
+    if env::var("MONO_CHAT_EVENT_BUS").is_err() {
+        env::set_var("MONO_CHAT_EVENT_BUS", "local");
+    }
+
+    chat_core::variables::delta::preflight_checks();
+    chat_core::variables::delta::preflight_redis().await;
+
+    let db = DatabaseInfo::Auto
+        .connect()
+        .await
+        .expect("Failed to connect to the database.");
+
+    db.migrate_database().await.unwrap();
+
+    presence_clear_region(None).await;
+
+    let authifier = chat_api::authifier(&db);
+
+    async_std::task::spawn(chat_core::tasks::start_workers(
+        db.clone(),
+        authifier.database.clone(),
+    ));
+    chat_core::tasks::start_sweepers(db.clone());
+
+    // Websocket server
+    chat_socket::database::set_db(db.clone());
+
+    let bind = env::var("MONO_CHAT_EXTERNAL_WS_URL").unwrap_or_else(|_| "0.0.0.0:9000".into());
+    async_std::task::spawn(chat_socket::listen(chat_socket::database::get_db(), bind));
+
+    // January runs on actix, which needs a system of its own.
+    thread::spawn(|| {
+        if let Err(err) = january::run() {
+            error!("January stopped: {err}");
+        }
+    });
+
+    // API server
+    if let Err(err) = chat_api::build(db, authifier).launch().await {
+        error!("API server stopped: {err}");
+    }
+}
@@ -25,11 +25,17 @@ deps() {
     crates/api/src \
     crates/core/src \
     crates/january/src \
-    crates/socket/src 
+    crates/socket/src \
+    crates/standalone/src
   echo 'fn main() { panic!("stub"); }' |
     tee crates/api/src/main.rs |
     tee crates/socket/src/main.rs |
-    tee crates/january/src/main.rs
+    tee crates/january/src/main.rs |
+    tee crates/standalone/src/main.rs
+  echo '' |
+    tee crates/api/src/lib.rs |
+    tee crates/socket/src/lib.rs |
+    tee crates/january/src/lib.rs
   echo 
     tee crates/core/src/lib.rs 
   
@@ -43,8 +49,12 @@ deps() {
 apps() {
   touch -am \
     crates/core/src/lib.rs \
+    crates/api/src/lib.rs \
     crates/api/src/main.rs \
+    crates/january/src/lib.rs \
     crates/january/src/main.rs \
+    crates/socket/src/lib.rs \
+    crates/standalone/src/main.rs \
     crates/core/voso/socket/main.rs
   
   if [ -z "$TARGETARCH" ]; then